@@ -0,0 +1,124 @@
+use std::num::NonZeroUsize;
+use std::thread;
+
+use answer::{Answer, Overflow};
+
+use crate::{CalibrationError, MissingDigits, DAY};
+
+/// Part 1 a byte at a time, for inputs too big to spend an allocation per line on
+pub fn part_1_bytes(input: &[u8]) -> Result<Answer, CalibrationError> {
+    let sum = sum_chunk(input).map_err(|missing| missing.into_error(input, 0))?;
+
+    Ok(Answer(sum.total.into()))
+}
+
+/// [`part_1_bytes`] split across a thread per core. The input is cut into roughly equal chunks
+/// on line boundaries, and the chunk totals added up in order afterwards so a digit-free line
+/// is still reported by its number in the whole input.
+pub fn part_1_parallel(input: &[u8]) -> Result<Answer, CalibrationError> {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunks = split_lines(input, threads);
+
+    let sums = thread::scope(|scope| {
+        let handles = chunks
+            .iter()
+            .map(|chunk| scope.spawn(|| sum_chunk(chunk)))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("summing a chunk shouldn't panic"))
+            .collect::<Vec<_>>()
+    });
+
+    let mut total: u64 = 0;
+    let mut lines = 0;
+    for (chunk, sum) in chunks.iter().zip(sums) {
+        let sum = sum.map_err(|missing| missing.into_error(chunk, lines))?;
+
+        total = total
+            .checked_add(sum.total)
+            .ok_or_else(|| Overflow::new(DAY, "summing calibration values"))?;
+        lines += sum.lines;
+    }
+
+    Ok(Answer(total.into()))
+}
+
+/// The calibration value of one line, found by looking in from either end for a digit
+fn process_line(line: &[u8]) -> Option<u64> {
+    let first = line.iter().find(|b| b.is_ascii_digit())?;
+    let last = line.iter().rev().find(|b| b.is_ascii_digit())?;
+
+    Some(u64::from(first - b'0') * 10 + u64::from(last - b'0'))
+}
+
+struct ChunkSum {
+    total: u64,
+    lines: usize,
+}
+
+/// Which line of a chunk had no digits, and where in the chunk it starts
+struct ChunkMissing {
+    line: usize,
+    start: usize,
+}
+
+impl ChunkMissing {
+    fn into_error(self, chunk: &[u8], lines_before: usize) -> CalibrationError {
+        let rest = &chunk[self.start..];
+        let end = memchr::memchr(b'\n', rest).unwrap_or(rest.len());
+        let text = rest[..end].strip_suffix(b"\r").unwrap_or(&rest[..end]);
+
+        CalibrationError::MissingDigits(MissingDigits {
+            line: lines_before + self.line + 1,
+            text: String::from_utf8_lossy(text).into_owned(),
+        })
+    }
+}
+
+// A line is at most 99, so a chunk would need well over 10^17 lines to overflow
+fn sum_chunk(chunk: &[u8]) -> Result<ChunkSum, ChunkMissing> {
+    let mut sum = ChunkSum { total: 0, lines: 0 };
+    let mut start = 0;
+
+    // Like `str::lines`, a newline at the very end doesn't start another line
+    while start < chunk.len() {
+        let end = memchr::memchr(b'\n', &chunk[start..]).map_or(chunk.len(), |i| start + i);
+
+        let value = process_line(&chunk[start..end]).ok_or(ChunkMissing {
+            line: sum.lines,
+            start,
+        })?;
+        sum.total += value;
+        sum.lines += 1;
+
+        start = end + 1;
+    }
+
+    Ok(sum)
+}
+
+/// Cuts `input` into at most `count` pieces, each ending just after a newline apart from the
+/// last
+fn split_lines(input: &[u8], count: usize) -> Vec<&[u8]> {
+    let size = input.len().div_ceil(count).max(1);
+    let mut chunks = vec![];
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let cut = match rest
+            .get(size..)
+            .and_then(|after| memchr::memchr(b'\n', after))
+        {
+            Some(i) => size + i + 1,
+            None => rest.len(),
+        };
+
+        let (chunk, after) = rest.split_at(cut);
+        chunks.push(chunk);
+        rest = after;
+    }
+
+    chunks
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+use std::str::FromStr;
+
+use answer::{Answer, Overflow};
+
+use crate::{VocabularyError, DAY};
+
+/// What to do about a line with no digits in it, which has no calibration value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// Stop at the line, naming it
+    #[default]
+    Error,
+    /// Leave the line out of the total, but keep a warning about it
+    Skip,
+    /// Count the line as worth zero without complaint
+    Zero,
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Policy::Error),
+            "skip" => Ok(Policy::Skip),
+            "zero" => Ok(Policy::Zero),
+            _ => Err(format!("Expected error, skip or zero, not {}", s)),
+        }
+    }
+}
+
+/// A line that no digits could be read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingDigits {
+    /// Counting from 1
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for MissingDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {} has no digits: {:?}", self.line, self.text)
+    }
+}
+
+/// The total of a document's calibration values, and the lines skipped to get it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calibration {
+    pub total: Answer,
+    pub skipped: Vec<MissingDigits>,
+}
+
+#[derive(Debug)]
+pub enum CalibrationError {
+    MissingDigits(MissingDigits),
+    Overflow(Overflow),
+    Io(std::io::Error),
+    Vocabulary(VocabularyError),
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalibrationError::MissingDigits(e) => e.fmt(f),
+            CalibrationError::Overflow(e) => e.fmt(f),
+            CalibrationError::Io(e) => write!(f, "Failed to read input: {}", e),
+            CalibrationError::Vocabulary(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+impl From<Overflow> for CalibrationError {
+    fn from(e: Overflow) -> Self {
+        CalibrationError::Overflow(e)
+    }
+}
+
+impl From<std::io::Error> for CalibrationError {
+    fn from(e: std::io::Error) -> Self {
+        CalibrationError::Io(e)
+    }
+}
+
+impl From<VocabularyError> for CalibrationError {
+    fn from(e: VocabularyError) -> Self {
+        CalibrationError::Vocabulary(e)
+    }
+}
+
+/// Sums the value `process_line` reads from each line, with `policy` deciding what happens to
+/// the lines it can't read anything from
+pub(crate) fn calibrate<L: AsRef<str>>(
+    lines: impl Iterator<Item = std::io::Result<L>>,
+    process_line: impl Fn(&str) -> Option<u32>,
+    policy: Policy,
+) -> Result<Calibration, CalibrationError> {
+    let mut calibration = Calibration {
+        total: Answer(0),
+        skipped: vec![],
+    };
+
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+
+        let value = match (process_line(line), policy) {
+            (Some(value), _) => value,
+            (None, Policy::Zero) => 0,
+            (None, policy) => {
+                let missing = MissingDigits {
+                    line: i + 1,
+                    text: line.to_string(),
+                };
+                if policy == Policy::Error {
+                    return Err(CalibrationError::MissingDigits(missing));
+                }
+
+                calibration.skipped.push(missing);
+                continue;
+            }
+        };
+
+        calibration.total =
+            calibration
+                .total
+                .checked_add(value, DAY, "summing calibration values")?;
+    }
+
+    Ok(calibration)
+}
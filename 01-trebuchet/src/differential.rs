@@ -0,0 +1,114 @@
+use answer::XorShift;
+
+use crate::vocabulary::PUZZLE;
+use crate::{process_line_part_2, process_line_part_2_opus};
+
+/// The letters of every spelled out digit, plus a numeral. Strings over anything else read
+/// the same either way, since neither variant can see a word in them.
+pub const ALPHABET: &[u8] = b"efghinorstuvwx1";
+
+/// A line [`process_line_part_2_opus`] reads differently to [`process_line_part_2`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    pub line: String,
+    pub expected: Option<u32>,
+    pub opus: Option<u32>,
+}
+
+pub fn check(line: &str) -> Option<Counterexample> {
+    let expected = process_line_part_2(line);
+    let opus = process_line_part_2_opus(line);
+
+    (expected != opus).then(|| Counterexample {
+        line: line.to_string(),
+        expected,
+        opus,
+    })
+}
+
+/// Checks every string over `alphabet` up to `max_len` long, shortest first. Only the minimal
+/// counterexamples are kept, so none of them contains another.
+pub fn exhaustive(alphabet: &[u8], max_len: usize) -> Vec<Counterexample> {
+    let mut found: Vec<Counterexample> = vec![];
+    let mut line = vec![];
+
+    for len in 1..=max_len {
+        let mut digits = vec![0; len];
+
+        'lines: loop {
+            line.clear();
+            line.extend(digits.iter().map(|d| alphabet[*d]));
+            let text = std::str::from_utf8(&line).expect("the alphabet should be ASCII");
+
+            if !found.iter().any(|c| text.contains(&c.line)) {
+                found.extend(check(text));
+            }
+
+            // Count up in base `alphabet.len()`
+            for d in digits.iter_mut().rev() {
+                *d += 1;
+                if *d < alphabet.len() {
+                    continue 'lines;
+                }
+                *d = 0;
+            }
+            break;
+        }
+    }
+
+    found
+}
+
+/// Checks `runs` random lines built out of whole words, numerals and stray letters, shrinking
+/// any counterexamples before they're returned. The same `seed` always checks the same lines.
+/// With `max_pieces` of 0 there's nothing to build lines out of, so none are checked.
+pub fn fuzz(seed: u64, runs: usize, max_pieces: usize) -> Vec<Counterexample> {
+    if max_pieces == 0 {
+        return vec![];
+    }
+
+    let mut rng = XorShift::new(seed);
+    let mut found: Vec<Counterexample> = vec![];
+
+    for _ in 0..runs {
+        let pieces = 1 + rng.below(max_pieces);
+        let mut line = String::new();
+        for _ in 0..pieces {
+            match rng.below(3) {
+                0 => line.push_str(PUZZLE[rng.below(PUZZLE.len())].0),
+                1 => line.push(char::from(b'0' + rng.below(10) as u8)),
+                _ => line.push(char::from(ALPHABET[rng.below(ALPHABET.len())])),
+            }
+        }
+
+        if check(&line).is_some() {
+            let counterexample = check(&shrink(&line)).unwrap();
+            if !found.contains(&counterexample) {
+                found.push(counterexample);
+            }
+        }
+    }
+
+    found.sort_by(|a, b| (a.line.len(), &a.line).cmp(&(b.line.len(), &b.line)));
+    found
+}
+
+/// Drops one character at a time from a counterexample for as long as what's left still is
+/// one, until no single character can go
+pub fn shrink(line: &str) -> String {
+    let mut line = line.to_string();
+
+    'shrinking: loop {
+        for (i, c) in line.char_indices() {
+            let mut shorter = line.clone();
+            shorter.replace_range(i..i + c.len_utf8(), "");
+
+            if check(&shorter).is_some() {
+                line = shorter;
+                continue 'shrinking;
+            }
+        }
+
+        return line;
+    }
+}
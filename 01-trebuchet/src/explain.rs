@@ -0,0 +1,76 @@
+use crate::{Scanner, Token, Vocabulary, VocabularyError};
+
+/// The first and last tokens part 1 and part 2 each found on one line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub line: &'a str,
+    pub part_1: Option<(Token, Token)>,
+    pub part_2: Option<(Token, Token)>,
+}
+
+impl Explanation<'_> {
+    pub fn part_1_value(&self) -> Option<u32> {
+        self.part_1.as_ref().map(value)
+    }
+
+    pub fn part_2_value(&self) -> Option<u32> {
+        self.part_2.as_ref().map(value)
+    }
+
+    /// Whether reading words as digits changed the line's calibration value
+    pub fn disagrees(&self) -> bool {
+        self.part_1_value() != self.part_2_value()
+    }
+
+    /// The line with each of part 2's tokens wrapped in `open` and `close`. Tokens that
+    /// overlap, like the `eight` and `two` of `eightwo`, are wrapped together.
+    pub fn highlight(&self, open: &str, close: &str) -> String {
+        let Some((first, last)) = &self.part_2 else {
+            return self.line.to_string();
+        };
+
+        let mut spans = vec![first.span.clone()];
+        if last.span.start < first.span.end {
+            spans[0].end = spans[0].end.max(last.span.end);
+        } else {
+            spans.push(last.span.clone());
+        }
+
+        let mut highlighted = String::new();
+        let mut from = 0;
+        for span in spans {
+            highlighted.push_str(&self.line[from..span.start]);
+            highlighted.push_str(open);
+            highlighted.push_str(&self.line[span.clone()]);
+            highlighted.push_str(close);
+            from = span.end;
+        }
+        highlighted.push_str(&self.line[from..]);
+
+        highlighted
+    }
+}
+
+fn value((first, last): &(Token, Token)) -> u32 {
+    first.value * 10 + last.value
+}
+
+/// Explains how every line of `input` is read, with `vocabulary` used for part 2
+pub fn explain<'a>(
+    input: &'a str,
+    vocabulary: &Vocabulary,
+) -> Result<Vec<Explanation<'a>>, VocabularyError> {
+    let numerals = Scanner::new(&Vocabulary::numerals())?;
+    let words = Scanner::new(vocabulary)?;
+
+    let tokens = |scanner: &Scanner, line| Some((scanner.first(line)?, scanner.last(line)?));
+
+    Ok(input
+        .lines()
+        .map(|line| Explanation {
+            line,
+            part_1: tokens(&numerals, line),
+            part_2: tokens(&words, line),
+        })
+        .collect())
+}
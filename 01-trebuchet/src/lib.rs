@@ -0,0 +1,355 @@
+use std::io::BufRead;
+use std::sync::OnceLock;
+
+use answer::Answer;
+use calibration::calibrate;
+use vocabulary::{DIGITS, PUZZLE};
+
+pub use bytes::{part_1_bytes, part_1_parallel};
+pub use calibration::{Calibration, CalibrationError, MissingDigits, Policy};
+pub use explain::{explain, Explanation};
+pub use scanner::{Scanner, Token};
+pub use vocabulary::{Vocabulary, VocabularyError};
+
+mod bytes;
+mod calibration;
+pub mod differential;
+mod explain;
+mod scanner;
+mod vocabulary;
+
+const DAY: u8 = 1;
+
+fn process_line_part_1(line: &str) -> Option<u32> {
+    let number_chars = line
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<Vec<_>>();
+
+    let combined = format!("{}{}", number_chars.first()?, number_chars.last()?);
+
+    Some(combined.parse::<u32>().unwrap())
+}
+
+pub fn part_1(input: &str) -> Result<Answer, CalibrationError> {
+    solve(input, process_line_part_1)
+}
+
+pub fn part_2(input: &str) -> Result<Answer, CalibrationError> {
+    solve(input, process_line_part_2)
+}
+
+/// Part 2 with the words of some other [`Vocabulary`] counting as digits, and `policy`
+/// deciding what to do with lines that have none
+pub fn part_2_with(
+    input: &str,
+    vocabulary: &Vocabulary,
+    policy: Policy,
+) -> Result<Calibration, CalibrationError> {
+    let scanner = Scanner::new(vocabulary)?;
+
+    calibrate(
+        input.lines().map(Ok),
+        |line| calibration_value(&scanner, line),
+        policy,
+    )
+}
+
+/// Part 2 using [`process_line_part_2_find`], which searches the line once per word from
+/// each end instead of scanning it a single time
+pub fn part_2_find(input: &str) -> Result<Answer, CalibrationError> {
+    solve(input, process_line_part_2_find)
+}
+
+/// Part 2 using [`process_line_part_2_opus`], which replaces the first and last spelled out
+/// digits with numerals and then reuses part 1.
+///
+/// This gets some lines wrong, see [`differential`]. [`part_2`] is the one to trust.
+pub fn part_2_opus(input: &str) -> Result<Answer, CalibrationError> {
+    solve(input, process_line_part_2_opus)
+}
+
+/// Like [`part_1`], but reads one line at a time so the input never has to fit in memory
+pub fn part_1_streaming(input: impl BufRead) -> Result<Answer, CalibrationError> {
+    calibrate(input.lines(), process_line_part_1, Policy::Error).map(|c| c.total)
+}
+
+/// Like [`part_2`], but reads one line at a time so the input never has to fit in memory
+pub fn part_2_streaming(input: impl BufRead) -> Result<Answer, CalibrationError> {
+    calibrate(input.lines(), process_line_part_2, Policy::Error).map(|c| c.total)
+}
+
+fn solve(input: &str, process_line: fn(&str) -> Option<u32>) -> Result<Answer, CalibrationError> {
+    calibrate(input.lines().map(Ok), process_line, Policy::Error).map(|c| c.total)
+}
+
+pub(crate) fn process_line_part_2(input: &str) -> Option<u32> {
+    static SCANNER: OnceLock<Scanner> = OnceLock::new();
+    // The puzzle's nine words are fixed, so they always compile
+    let scanner = SCANNER.get_or_init(|| {
+        Scanner::new(&Vocabulary::puzzle()).expect("the puzzle's words fit in a DFA")
+    });
+
+    calibration_value(scanner, input)
+}
+
+fn calibration_value(scanner: &Scanner, line: &str) -> Option<u32> {
+    let first = scanner.first(line)?;
+    let last = scanner.last(line)?;
+
+    Some((first.value * 10) + last.value)
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Indexes {
+    pos: usize,
+    num: u32,
+}
+
+fn process_line_part_2_find(input: &str) -> Option<u32> {
+    let searches = || PUZZLE.iter().chain(DIGITS);
+
+    let mut found = searches()
+        .filter_map(|(word, num)| input.find(word).map(|pos| Indexes { pos, num: *num }))
+        .collect::<Vec<_>>();
+    let mut last_found = searches()
+        .filter_map(|(word, num)| input.rfind(word).map(|pos| Indexes { pos, num: *num }))
+        .collect::<Vec<_>>();
+
+    found.append(&mut last_found);
+
+    found.sort_by_key(|x| x.pos);
+
+    let first = found.first()?;
+    let last = found.last()?;
+
+    let ans = (first.num * 10) + last.num;
+
+    // dbg!(input, ans);
+
+    Some(ans)
+}
+
+// Replacing a word can eat into one that overlaps it, like the `one` of `twone`, and the
+// "last" word is the one whose first appearance is last, so `twosixfivesix` reads as 25
+pub(crate) fn process_line_part_2_opus(line: &str) -> Option<u32> {
+    let replacements = vec![
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ];
+    let mut actual_replacements = replacements
+        .iter()
+        .filter_map(|(word, num)| {
+            let pos = line.find(word);
+            pos.map(|pos| (pos, word, num))
+        })
+        .collect::<Vec<_>>();
+    actual_replacements.sort_by_key(|x| x.0);
+
+    let mut line = line.to_string();
+
+    if let Some(first_replacement) = actual_replacements.first() {
+        line = line.replace(first_replacement.1, &first_replacement.2.to_string());
+    };
+    if let Some(last_replacement) = actual_replacements.last() {
+        line = line.replace(last_replacement.1, &last_replacement.2.to_string());
+    }
+
+    process_line_part_1(&line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total(input: &str, vocabulary: &Vocabulary) -> Answer {
+        part_2_with(input, vocabulary, Policy::Error).unwrap().total
+    }
+
+    #[test]
+    fn edge_case() {
+        let line = "mtthreeclxhfivep8threelh";
+        let ans = process_line_part_2(line);
+        assert_eq!(ans, Some(33));
+    }
+
+    #[test]
+    fn overlapping_words() {
+        assert_eq!(process_line_part_2("eightwo"), Some(82));
+        assert_eq!(process_line_part_2("xoneightx"), Some(18));
+        assert_eq!(process_line_part_2_find("eightwo"), Some(82));
+    }
+
+    #[test]
+    fn digit_free_lines() {
+        let input = "1abc2\n\nnothing here\nfive";
+
+        assert!(matches!(
+            part_1(input),
+            Err(CalibrationError::MissingDigits(MissingDigits {
+                line: 2,
+                ..
+            }))
+        ));
+
+        let skipped = part_2_with(input, &Vocabulary::puzzle(), Policy::Skip).unwrap();
+        assert_eq!(skipped.total, Answer(67));
+        assert_eq!(
+            skipped.skipped,
+            vec![
+                MissingDigits {
+                    line: 2,
+                    text: "".to_string()
+                },
+                MissingDigits {
+                    line: 3,
+                    text: "nothing here".to_string()
+                }
+            ]
+        );
+
+        let zeroed = part_2_with(input, &Vocabulary::puzzle(), Policy::Zero).unwrap();
+        assert_eq!(zeroed.total, Answer(67));
+        assert!(zeroed.skipped.is_empty());
+    }
+
+    #[test]
+    fn byte_level_part_1() {
+        let input = include_str!("my.input");
+
+        assert_eq!(part_1_bytes(input.as_bytes()).unwrap(), Answer(55477));
+        assert_eq!(part_1_parallel(input.as_bytes()).unwrap(), Answer(55477));
+        assert_eq!(part_1_bytes(b"a1b\r\nc2d3\n").unwrap(), Answer(34));
+
+        // Far enough in that the line lands in a later chunk than the first
+        let mut input = "12\n".repeat(1000);
+        input.push_str("none\r\n34\n");
+        assert!(matches!(
+            part_1_parallel(input.as_bytes()),
+            Err(CalibrationError::MissingDigits(MissingDigits { line: 1001, ref text })) if text == "none"
+        ));
+    }
+
+    #[test]
+    fn token_spans() {
+        let scanner = Scanner::new(&Vocabulary::puzzle()).unwrap();
+        let line = "4nineeightseven2";
+
+        assert_eq!(
+            scanner.first(line),
+            Some(Token {
+                span: 0..1,
+                value: 4
+            })
+        );
+        assert_eq!(
+            scanner.first("xtwone3"),
+            Some(Token {
+                span: 1..4,
+                value: 2
+            })
+        );
+        assert_eq!(
+            scanner.last("xtwone"),
+            Some(Token {
+                span: 3..6,
+                value: 1
+            })
+        );
+        assert_eq!(scanner.last("abc"), None);
+    }
+
+    #[test]
+    fn builtin_vocabularies() {
+        let english = Vocabulary::builtin("english").unwrap();
+        assert_eq!(total("zero7one", &english), Answer(1));
+
+        let german = Vocabulary::builtin("german").unwrap();
+        assert_eq!(total("xfünfzweiacht", &german), Answer(58));
+
+        let roman = Vocabulary::builtin("roman").unwrap();
+        assert_eq!(total("chapter VIII verse IX", &roman), Answer(89));
+        assert_eq!(total("IV and VII", &roman), Answer(47));
+
+        let shouting = Vocabulary::puzzle().case_insensitive();
+        assert_eq!(total("ONEtwoTHREE", &shouting), Answer(13));
+    }
+
+    #[test]
+    fn explains_lines() {
+        let explanations = explain("two1nine\neightwo3\nnothing", &Vocabulary::puzzle()).unwrap();
+
+        assert_eq!(explanations[0].highlight("[", "]"), "[two]1[nine]");
+        assert_eq!(explanations[0].part_2_value(), Some(29));
+        assert!(explanations[0].disagrees());
+
+        let (first, last) = explanations[1].part_2.clone().unwrap();
+        assert_eq!((first.span, last.span), (0..5, 7..8));
+        assert_eq!(explanations[1].part_1_value(), Some(33));
+        assert_eq!(explanations[1].part_2_value(), Some(83));
+
+        assert_eq!(explanations[2].part_2, None);
+        assert_eq!(explanations[2].highlight("[", "]"), "nothing");
+        assert!(!explanations[2].disagrees());
+
+        let overlapping = &explain("eightwo", &Vocabulary::puzzle()).unwrap()[0];
+        assert_eq!(overlapping.highlight("[", "]"), "[eightwo]");
+    }
+
+    #[test]
+    fn opus_misreads_lines() {
+        assert!(differential::exhaustive(differential::ALPHABET, 4).is_empty());
+        assert_eq!(
+            differential::exhaustive(b"1enotw", 5),
+            vec![differential::Counterexample {
+                line: "twone".to_string(),
+                expected: Some(21),
+                opus: Some(22),
+            }]
+        );
+
+        let sixes = differential::check("twosixfivesix").unwrap();
+        assert_eq!((sixes.expected, sixes.opus), (Some(26), Some(25)));
+
+        for counterexample in differential::fuzz(1, 1000, 8) {
+            assert_eq!(
+                differential::shrink(&counterexample.line),
+                counterexample.line
+            );
+        }
+        assert!(differential::fuzz(1, 1000, 0).is_empty());
+    }
+
+    // The scanner and the word by word search agree with each other and with the accepted
+    // answer, so they're authoritative and the replacing variant is the one that's wrong
+    #[test]
+    fn authoritative_part_2() {
+        let input = include_str!("my.input").trim();
+
+        assert_eq!(part_2(input).unwrap(), Answer(54431));
+        assert_eq!(part_2_find(input).unwrap(), Answer(54431));
+        assert_eq!(part_2_opus(input).unwrap(), Answer(54442));
+    }
+
+    #[test]
+    fn vocabulary_file() {
+        let vocabulary = Vocabulary::parse("# Dutch\neen = 1\n\ntwee = 2 # two\n").unwrap();
+        assert_eq!(total("tweexeen", &vocabulary), Answer(21));
+
+        assert!(matches!(
+            Vocabulary::parse("een = 1\ntwee = 12\n"),
+            Err(VocabularyError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Vocabulary::parse("# nothing\n"),
+            Err(VocabularyError::Empty)
+        ));
+    }
+}
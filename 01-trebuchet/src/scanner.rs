@@ -0,0 +1,121 @@
+use std::ops::Range;
+
+use aho_corasick::automaton::Automaton;
+use aho_corasick::dfa::DFA;
+use aho_corasick::{Anchored, MatchKind};
+
+use crate::{Vocabulary, VocabularyError};
+
+/// A number found in a calibration line, and the bytes it was spelled with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub span: Range<usize>,
+    pub value: u32,
+}
+
+/// Finds the first and last numbers in a line, whether written as digits or words.
+///
+/// All of the words are compiled into one automaton up front, plus a second one over the
+/// reversed words so the last number can be found by walking in from the end of the line.
+/// Matches are allowed to overlap, so `eightwo` has `eight` first and `two` last.
+pub struct Scanner {
+    forward: DFA,
+    backward: DFA,
+    values: Vec<u32>,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: &Vocabulary) -> Result<Self, VocabularyError> {
+        let (words, values): (Vec<_>, Vec<_>) = vocabulary.entries().unzip();
+        let forward_words = words
+            .iter()
+            .map(|w| w.bytes().collect())
+            .collect::<Vec<_>>();
+        let reversed_words = words
+            .iter()
+            .map(|w| w.bytes().rev().collect())
+            .collect::<Vec<_>>();
+
+        // Standard semantics report every word ending at a position, overlapping or not
+        let build = |patterns: &[Vec<u8>]| {
+            DFA::builder()
+                .match_kind(MatchKind::Standard)
+                .ascii_case_insensitive(vocabulary.is_case_insensitive())
+                .build(patterns)
+                .map_err(VocabularyError::Automaton)
+        };
+
+        Ok(Self {
+            forward: build(&forward_words)?,
+            backward: build(&reversed_words)?,
+            values,
+        })
+    }
+
+    pub fn first(&self, line: &str) -> Option<Token> {
+        let bytes = line.as_bytes();
+        let (start, len, index) = earliest(&self.forward, bytes.iter().copied())?;
+
+        Some(Token {
+            span: start..start + len,
+            value: self.values[index],
+        })
+    }
+
+    pub fn last(&self, line: &str) -> Option<Token> {
+        let bytes = line.as_bytes();
+        let (from_end, len, index) = earliest(&self.backward, bytes.iter().rev().copied())?;
+
+        let end = bytes.len() - from_end;
+        Some(Token {
+            span: end - len..end,
+            value: self.values[index],
+        })
+    }
+}
+
+/// Feeds `bytes` through `dfa` and returns the match that starts soonest, as the offset it
+/// starts at, its length, and which word it was.
+///
+/// Words can be of different lengths, so the first match to finish isn't always the first to
+/// start. Scanning carries on until no longer word could still start earlier, and prefers the
+/// longer word when two start at the same place.
+fn earliest(dfa: &DFA, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize, usize)> {
+    let max_len = dfa.max_pattern_len();
+    let mut state = dfa.start_state(Anchored::No).ok()?;
+    let mut best: Option<(usize, usize, usize)> = None;
+
+    for (i, byte) in bytes.enumerate() {
+        if let Some((start, _, _)) = best {
+            if i + 1 > start + max_len {
+                break;
+            }
+        }
+
+        state = dfa.next_state(Anchored::No, state, byte);
+        if dfa.is_dead(state) {
+            break;
+        }
+        if !dfa.is_match(state) {
+            continue;
+        }
+
+        for m in 0..dfa.match_len(state) {
+            let pattern = dfa.match_pattern(state, m);
+            let len = dfa.pattern_len(pattern);
+            let start = i + 1 - len;
+
+            let better = match best {
+                None => true,
+                Some((best_start, best_len, _)) => {
+                    start < best_start || (start == best_start && len > best_len)
+                }
+            };
+            if better {
+                best = Some((start, len, pattern.as_usize()));
+            }
+        }
+    }
+
+    best
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+use std::path::Path;
+
+/// The numerals, which count in every vocabulary
+pub(crate) const DIGITS: &[(&str, u32)] = &[
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// The words the puzzle itself spells out, which leaves out `zero`
+pub(crate) const PUZZLE: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const ENGLISH: &[(&str, u32)] = &[
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const GERMAN: &[(&str, u32)] = &[
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const FRENCH: &[(&str, u32)] = &[
+    ("zéro", 0),
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+const SPANISH: &[(&str, u32)] = &[
+    ("cero", 0),
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+const ROMAN: &[(&str, u32)] = &[
+    ("I", 1),
+    ("II", 2),
+    ("III", 3),
+    ("IV", 4),
+    ("V", 5),
+    ("VI", 6),
+    ("VII", 7),
+    ("VIII", 8),
+    ("IX", 9),
+];
+
+/// The words that count as digits on a calibration line, on top of the numerals themselves.
+///
+/// Either one of the [built in](Vocabulary::BUILTIN) sets, or read from a file with one
+/// `word = value` per line, where `#` starts a comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    case_insensitive: bool,
+}
+
+impl Vocabulary {
+    pub const BUILTIN: &'static [&'static str] = &[
+        "numerals", "puzzle", "english", "german", "french", "spanish", "roman",
+    ];
+
+    /// The vocabulary part 2 of the puzzle is solved with
+    pub fn puzzle() -> Self {
+        Self::from_table(PUZZLE)
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        let table = match name {
+            "numerals" => &[],
+            "puzzle" => PUZZLE,
+            "english" => ENGLISH,
+            "german" => GERMAN,
+            "french" => FRENCH,
+            "spanish" => SPANISH,
+            "roman" => ROMAN,
+            _ => return None,
+        };
+
+        Some(Self::from_table(table))
+    }
+
+    /// No words at all, so only the numerals count, the way part 1 reads a line
+    pub fn numerals() -> Self {
+        Self::from_table(&[])
+    }
+
+    /// Reads a vocabulary file, see [`Vocabulary::parse`]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, VocabularyError> {
+        let text = std::fs::read_to_string(path).map_err(VocabularyError::Io)?;
+
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, VocabularyError> {
+        let mut words = vec![];
+
+        for (i, line) in text.lines().enumerate() {
+            let error = |message: &str| VocabularyError::Parse {
+                line: i + 1,
+                message: message.to_string(),
+            };
+
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (word, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected word = value"))?;
+            let (word, value) = (word.trim(), value.trim());
+            if word.is_empty() {
+                return Err(error("the word is empty"));
+            }
+
+            let value = value
+                .parse::<u32>()
+                .ok()
+                .filter(|v| *v <= 9)
+                .ok_or_else(|| error("the value must be a single digit"))?;
+
+            words.push((word.to_string(), value));
+        }
+
+        if words.is_empty() {
+            return Err(VocabularyError::Empty);
+        }
+
+        Ok(Self {
+            words,
+            case_insensitive: false,
+        })
+    }
+
+    /// Matches words regardless of case. Only ASCII letters are folded, so `FÜNF` won't match
+    /// `fünf`.
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Every word and its value, followed by the numerals
+    pub fn entries(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+            .chain(DIGITS.iter().copied())
+    }
+
+    fn from_table(table: &[(&str, u32)]) -> Self {
+        Self {
+            words: table
+                .iter()
+                .map(|(word, value)| (word.to_string(), *value))
+                .collect(),
+            case_insensitive: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum VocabularyError {
+    Io(std::io::Error),
+    Parse {
+        line: usize,
+        message: String,
+    },
+    Empty,
+    /// The words couldn't be compiled into the automaton that scans for them
+    Automaton(aho_corasick::BuildError),
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VocabularyError::Io(e) => write!(f, "Failed to read vocabulary: {}", e),
+            VocabularyError::Parse { line, message } => {
+                write!(f, "Vocabulary line {}: {}", line, message)
+            }
+            VocabularyError::Empty => write!(f, "Vocabulary has no words"),
+            VocabularyError::Automaton(e) => write!(f, "Vocabulary can't be scanned for: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
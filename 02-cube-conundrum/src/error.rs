@@ -0,0 +1,76 @@
+use answer::Overflow;
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+/// Why a game couldn't be parsed, pointing at where in the input it went wrong
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
+pub enum ParseError {
+    #[error("Expected the game to start with `Game N:`")]
+    #[diagnostic(code(cube_conundrum::missing_prefix))]
+    MissingPrefix {
+        #[label("no `Game N:` here")]
+        span: SourceSpan,
+    },
+
+    #[error("Expected a game id, not `{id}`")]
+    #[diagnostic(code(cube_conundrum::bad_game_id))]
+    BadGameId {
+        id: String,
+        #[label("not a number")]
+        span: SourceSpan,
+    },
+
+    #[error("Draw has no cubes in it")]
+    #[diagnostic(
+        code(cube_conundrum::empty_draw),
+        help("draws are separated by `;`, so there may be one too many")
+    )]
+    EmptyDraw {
+        #[label("empty draw")]
+        span: SourceSpan,
+    },
+
+    #[error("Expected a count and a color, like `3 blue`")]
+    #[diagnostic(code(cube_conundrum::bad_cubes))]
+    BadCubes {
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("Expected a number of cubes, not `{count}`")]
+    #[diagnostic(code(cube_conundrum::bad_count))]
+    BadCount {
+        count: String,
+        #[label("not a number")]
+        span: SourceSpan,
+    },
+
+    #[error("{color} isn't a color in the palette")]
+    #[diagnostic(code(cube_conundrum::unknown_color))]
+    UnknownColor {
+        color: String,
+        #[label("unknown color")]
+        span: SourceSpan,
+    },
+
+    #[error("{color} is drawn twice in one draw")]
+    #[diagnostic(code(cube_conundrum::duplicate_color))]
+    DuplicateColor {
+        color: String,
+        #[label("first here")]
+        first: SourceSpan,
+        #[label("then again here")]
+        again: SourceSpan,
+    },
+}
+
+/// Why a bag couldn't be estimated
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
+pub enum EstimateError {
+    /// Every count from none up to this many is weighed, so it has to be kept within reason
+    #[error("Can't weigh bags with up to {count} {color} cubes, the most is {max}")]
+    #[diagnostic(code(cube_conundrum::too_many_cubes))]
+    TooManyCubes { color: String, count: u32, max: u32 },
+    #[error("{0}")]
+    Overflow(#[from] Overflow),
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+
+use answer::{Answer, CheckedAnswer, Overflow};
+
+use crate::{CubeDraw, EstimateError, Game, Games, Palette, DAY};
+
+/// How far to look for the bag the draws came from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimator {
+    /// The most cubes of any one color a bag is considered to have, unless a draw showed more.
+    /// Every bag up to this is equally likely before the draws are seen.
+    pub limit: u32,
+    /// How much of the posterior the range of totals should cover
+    pub level: f64,
+}
+
+impl Default for Estimator {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            level: 0.9,
+        }
+    }
+}
+
+/// What the draws say about the bag they were taken from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    /// The bag the draws were most likely to have come from. That's always the smallest one
+    /// that could have produced them, so its power is the one part 2 uses.
+    pub most_likely: CubeDraw,
+    /// The posterior mean of each color's count
+    pub expected: BTreeMap<String, f64>,
+    /// The narrowest range of bag totals holding [`Estimator::level`] of the posterior, always
+    /// taking in the most likely bag's total
+    pub total_range: (u32, u32),
+}
+
+impl Estimate {
+    pub fn most_likely_power(&self) -> Result<Answer, Overflow> {
+        self.most_likely
+            .counts
+            .values()
+            .copied()
+            .checked_product(DAY, "computing the power of an estimated bag")
+    }
+
+    /// The posterior mean of the power. Colors are independent under the posterior, so this
+    /// is the product of their means.
+    pub fn expected_power(&self) -> f64 {
+        self.expected.values().product()
+    }
+
+    pub fn most_likely_total(&self) -> Result<u32, Overflow> {
+        self.most_likely.total()
+    }
+}
+
+impl Estimator {
+    /// The most cubes of one color any estimate weighs. Every count up to the limit, or up to
+    /// the most drawn if that's more, gets a weight, and the colors' weights are convolved
+    /// together, so a bag much bigger than this would take too long and too much memory.
+    pub const MAX_CUBES: u32 = 10_000;
+
+    /// Estimates each game's bag on its own
+    pub fn games(
+        &self,
+        games: &Games,
+        palette: &Palette,
+    ) -> Result<Vec<(u32, Estimate)>, EstimateError> {
+        games
+            .games
+            .iter()
+            .map(|game| Ok((game.id, self.estimate(&[game], palette)?)))
+            .collect()
+    }
+
+    /// Estimates the one bag every game was played with
+    pub fn shared(&self, games: &Games, palette: &Palette) -> Result<Estimate, EstimateError> {
+        self.estimate(&games.games.iter().collect::<Vec<_>>(), palette)
+    }
+
+    // A draw is taken to show anywhere from none to all of the bag's cubes of each color, all
+    // equally likely, and the cubes go back before the next draw. With `k` of a color in the
+    // bag, `d` draws are then `(k + 1)^-d` likely for any `k` at least the most seen, which
+    // makes that smallest bag the most likely one and leaves the posterior to say how much
+    // bigger the bag could be. Colors are independent, so the posterior over the total is the
+    // colors' posteriors convolved together.
+    fn estimate(&self, games: &[&Game], palette: &Palette) -> Result<Estimate, EstimateError> {
+        let draws = games.iter().map(|g| g.cube_draws.len()).sum::<usize>() as f64;
+
+        let mut minimum = CubeDraw::default();
+        for game in games {
+            minimum.union(&game.minimum_bag());
+        }
+        let colors = palette.power_colors(&minimum).collect::<Vec<_>>();
+
+        let mut expected = BTreeMap::new();
+        let mut totals = vec![1.0];
+
+        for color in &colors {
+            let seen = minimum.count(color);
+            let limit = self.limit.max(seen);
+            if limit > Self::MAX_CUBES {
+                return Err(EstimateError::TooManyCubes {
+                    color: color.to_string(),
+                    count: limit,
+                    max: Self::MAX_CUBES,
+                });
+            }
+            let (seen, limit) = (seen as usize, limit as usize);
+
+            let ln_weights = (0..=limit)
+                .map(|k| {
+                    if k < seen {
+                        f64::NEG_INFINITY
+                    } else {
+                        -draws * ((k + 1) as f64).ln()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let weights = normalise(&ln_weights);
+            let mean = weights
+                .iter()
+                .enumerate()
+                .map(|(k, w)| k as f64 * w)
+                .sum::<f64>();
+            expected.insert(color.to_string(), mean);
+
+            totals = convolve(&totals, &weights);
+        }
+
+        let most_likely = CubeDraw::new(colors.iter().map(|c| (*c, minimum.count(c))));
+        let most_likely_total = most_likely.total()?;
+
+        Ok(Estimate {
+            most_likely,
+            expected,
+            total_range: highest_density(&totals, self.level, most_likely_total),
+        })
+    }
+}
+
+/// Turns logs of unnormalised probabilities into probabilities that sum to one
+fn normalise(ln_weights: &[f64]) -> Vec<f64> {
+    let peak = ln_weights.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let weights = ln_weights
+        .iter()
+        .map(|w| (w - peak).exp())
+        .collect::<Vec<_>>();
+    let total = weights.iter().sum::<f64>();
+
+    weights.iter().map(|w| w / total).collect()
+}
+
+/// The distribution of the sum of two independent counts
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut sum = vec![0.0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            sum[i + j] += x * y;
+        }
+    }
+
+    sum
+}
+
+/// The range of totals covering the likeliest ones until they hold `level` of a distribution,
+/// stretched to take in `include`.
+///
+/// The most likely bag needn't have the most likely total, since bigger totals can be made up
+/// by more bags, and a range leaving out the bag the estimate is built around would read oddly.
+fn highest_density(weights: &[f64], level: f64, include: u32) -> (u32, u32) {
+    let mut likeliest = (0..weights.len()).collect::<Vec<_>>();
+    likeliest.sort_by(|a, b| weights[*b].total_cmp(&weights[*a]));
+
+    let (mut low, mut high) = (include as usize, include as usize);
+    let mut held = 0.0;
+    for n in likeliest {
+        if held >= level {
+            break;
+        }
+        held += weights[n];
+        low = low.min(n);
+        high = high.max(n);
+    }
+
+    (low as u32, high as u32)
+}
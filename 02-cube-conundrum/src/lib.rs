@@ -0,0 +1,710 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io::BufRead;
+
+use answer::{offset, Answer, CheckedAnswer, OrOverflow, Overflow};
+use miette::{miette, IntoDiagnostic, NamedSource, Report, Result, SourceSpan};
+
+pub use error::{EstimateError, ParseError};
+pub use estimate::{Estimate, Estimator};
+
+mod error;
+mod estimate;
+
+const DAY: u8 = 2;
+
+/// Which colors of cube a game may mention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Palette {
+    /// Any color at all
+    Open,
+    /// Only these colors, anything else is an error
+    Only(BTreeSet<String>),
+}
+
+impl Palette {
+    /// The red, green and blue cubes the puzzle uses
+    pub fn classic() -> Self {
+        Self::only(["red", "green", "blue"])
+    }
+
+    pub fn only<'a>(colors: impl IntoIterator<Item = &'a str>) -> Self {
+        Palette::Only(colors.into_iter().map(|c| c.to_string()).collect())
+    }
+
+    fn contains(&self, color: &str) -> bool {
+        match self {
+            Palette::Open => true,
+            Palette::Only(colors) => colors.contains(color),
+        }
+    }
+
+    /// The colors a power is the product of. A closed palette counts every color in it, even
+    /// ones the bag has none of, while an open one can only go by what's in the bag.
+    fn power_colors<'a>(&'a self, bag: &'a CubeDraw) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match self {
+            Palette::Open => Box::new(bag.colors()),
+            Palette::Only(colors) => Box::new(colors.iter().map(|c| c.as_str())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Game {
+    id: u32,
+    cube_draws: Vec<CubeDraw>,
+}
+
+impl Game {
+    /// Parses one line of the input, which starts `start` bytes in so that errors can point
+    /// at the right place
+    fn parse(input: &str, start: usize, palette: &Palette) -> Result<Self, ParseError> {
+        let span = |part: &str| span(start, input, part);
+
+        let Some((game_id_section, cube_draws_section)) = input.split_once(':') else {
+            return Err(ParseError::MissingPrefix { span: span(input) });
+        };
+
+        let game_id = game_id_section
+            .strip_prefix("Game")
+            .filter(|id| id.starts_with(char::is_whitespace))
+            .ok_or(ParseError::MissingPrefix {
+                span: span(game_id_section),
+            })?
+            .trim();
+        let game_id = game_id.parse::<u32>().map_err(|_| ParseError::BadGameId {
+            id: game_id.to_string(),
+            span: span(game_id),
+        })?;
+
+        let cube_draws = cube_draws_section
+            .split(';')
+            .map(|cd| CubeDraw::parse(cd, start + offset(input, cd), palette))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Game {
+            id: game_id,
+            cube_draws,
+        })
+    }
+
+    fn valid_for(&self, validate_against: &CubeDraw) -> bool {
+        self.ruled_out_by(validate_against).is_none()
+    }
+
+    /// The first draw that took more of a color than `bag` has, if there is one
+    fn ruled_out_by(&self, bag: &CubeDraw) -> Option<RuledOut> {
+        self.cube_draws.iter().enumerate().find_map(|(draw, cd)| {
+            let (color, drawn) = cd.exceeds(bag)?;
+
+            Some(RuledOut {
+                game: self.id,
+                draw: draw + 1,
+                color: color.to_string(),
+                drawn,
+                in_bag: bag.count(color),
+            })
+        })
+    }
+
+    /// The fewest cubes of each color the bag could have held
+    fn minimum_bag(&self) -> CubeDraw {
+        let mut minimum_cube_count = CubeDraw::default();
+
+        for cd in &self.cube_draws {
+            minimum_cube_count.union(cd);
+        }
+
+        minimum_cube_count
+    }
+
+    fn minumum_power(&self, palette: &Palette) -> Result<Answer, Overflow> {
+        let minimum_cube_count = self.minimum_bag();
+
+        palette
+            .power_colors(&minimum_cube_count)
+            .map(|color| minimum_cube_count.count(color))
+            .checked_product(DAY, &format!("computing the power of game {}", self.id))
+    }
+}
+
+/// How many cubes of each color were drawn at once, or are in a bag. Colors that aren't
+/// mentioned have none.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CubeDraw {
+    counts: BTreeMap<String, u32>,
+}
+
+impl CubeDraw {
+    pub fn new<'a>(counts: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self {
+            counts: counts
+                .into_iter()
+                .map(|(color, count)| (color.to_string(), count))
+                .collect(),
+        }
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    /// How many cubes there are of every color together
+    pub fn total(&self) -> Result<u32, Overflow> {
+        self.counts
+            .values()
+            .try_fold(0_u32, |total, count| total.checked_add(*count))
+            .or_overflow(DAY, "totalling the cubes in a bag")
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(|c| c.as_str())
+    }
+
+    fn parse(input: &str, start: usize, palette: &Palette) -> Result<Self, ParseError> {
+        let span = |part: &str| span(start, input, part);
+
+        if input.trim().is_empty() {
+            return Err(ParseError::EmptyDraw { span: span(input) });
+        }
+
+        let mut counts = BTreeMap::new();
+        let mut color_spans = BTreeMap::new();
+        for cubes in input.split(',') {
+            // Each is a count then a color, like "8 green"
+            let [count, color] = cubes.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err(ParseError::BadCubes { span: span(cubes) });
+            };
+
+            let count = count.parse::<u32>().map_err(|_| ParseError::BadCount {
+                count: count.to_string(),
+                span: span(count),
+            })?;
+
+            if !palette.contains(color) {
+                return Err(ParseError::UnknownColor {
+                    color: color.to_string(),
+                    span: span(color),
+                });
+            }
+            if let Some(first) = color_spans.insert(color, span(color)) {
+                return Err(ParseError::DuplicateColor {
+                    color: color.to_string(),
+                    first,
+                    again: span(color),
+                });
+            }
+
+            counts.insert(color.to_string(), count);
+        }
+
+        Ok(CubeDraw { counts })
+    }
+
+    /// The first color there's more of in this draw than in `bag`, and how many were drawn
+    fn exceeds(&self, bag: &CubeDraw) -> Option<(&str, u32)> {
+        self.counts
+            .iter()
+            .find(|(color, count)| **count > bag.count(color))
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    /// The most of each color there is in either draw
+    fn union(&mut self, other: &CubeDraw) {
+        for (color, count) in &other.counts {
+            let most = self.counts.entry(color.clone()).or_default();
+            *most = (*most).max(*count);
+        }
+    }
+}
+
+impl fmt::Display for CubeDraw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self
+            .counts
+            .iter()
+            .map(|(color, count)| format!("{} {}", count, color))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+/// The draw that shows a game can't have come from a bag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuledOut {
+    pub game: u32,
+    /// Counting from 1, in the order the game lists them
+    pub draw: usize,
+    pub color: String,
+    pub drawn: u32,
+    pub in_bag: u32,
+}
+
+impl fmt::Display for RuledOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {} draw {} took {} {} but the bag only has {}",
+            self.game, self.draw, self.drawn, self.color, self.in_bag
+        )
+    }
+}
+
+/// Which games could have come from a bag, and what rules out the rest
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Feasibility {
+    pub possible: Vec<u32>,
+    pub impossible: Vec<RuledOut>,
+}
+
+/// A set of parsed games to ask about bags
+#[derive(Debug, PartialEq)]
+pub struct Games {
+    games: Vec<Game>,
+}
+
+impl Games {
+    pub fn parse(input: &str, palette: &Palette) -> Result<Self> {
+        Ok(Self {
+            games: parse_games(input, palette)?,
+        })
+    }
+
+    /// The smallest bag that every one of the games is possible with
+    pub fn minimal_bag(&self) -> CubeDraw {
+        let mut bag = CubeDraw::default();
+        for game in &self.games {
+            bag.union(&game.minimum_bag());
+        }
+
+        bag
+    }
+
+    pub fn feasibility(&self, bag: &CubeDraw) -> Feasibility {
+        let mut feasibility = Feasibility::default();
+
+        for game in &self.games {
+            match game.ruled_out_by(bag) {
+                Some(ruled_out) => feasibility.impossible.push(ruled_out),
+                None => feasibility.possible.push(game.id),
+            }
+        }
+
+        feasibility
+    }
+}
+
+/// The bag part 1 asks about
+pub fn part_1_bag() -> CubeDraw {
+    CubeDraw::new([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+pub fn part_1(input: &str) -> Result<Answer> {
+    part_1_with(input, &Palette::classic(), &part_1_bag())
+}
+
+/// Part 1 for any `bag`, with `palette` deciding which colors the games may use
+pub fn part_1_with(input: &str, palette: &Palette, bag: &CubeDraw) -> Result<Answer> {
+    if let Some(color) = bag.colors().find(|c| !palette.contains(c)) {
+        return Err(miette!("{} isn't a color in the palette", color));
+    }
+
+    let games = parse_games(input, palette)?;
+
+    let valid_games = games
+        .iter()
+        .filter(|g| g.valid_for(bag))
+        .collect::<Vec<_>>();
+
+    valid_games
+        .iter()
+        .map(|g| g.id)
+        .checked_sum(DAY, "summing game ids")
+        .into_diagnostic()
+}
+
+pub fn part_2(input: &str) -> Result<Answer> {
+    part_2_with(input, &Palette::classic())
+}
+
+/// Part 2 with `palette` deciding which colors the games may use, and so which colors the
+/// power is a product of
+pub fn part_2_with(input: &str, palette: &Palette) -> Result<Answer> {
+    let games = parse_games(input, palette)?;
+
+    let powers = games
+        .iter()
+        .map(|g| g.minumum_power(palette))
+        .collect::<Result<Vec<_>, _>>()
+        .into_diagnostic()?;
+
+    powers
+        .into_iter()
+        .map(|p| p.0)
+        .checked_sum(DAY, "summing game powers")
+        .into_diagnostic()
+}
+
+fn parse_games(input: &str, palette: &Palette) -> Result<Vec<Game>> {
+    input
+        .lines()
+        .map(|line| {
+            Game::parse(line, offset(input, line), palette)
+                .map_err(|e| Report::new(e).with_source_code(input.to_string()))
+        })
+        .collect()
+}
+
+/// Parses a game read on its own, so errors only show the one line
+fn parse_streamed_game(line: &str, number: usize, palette: &Palette) -> Result<Game> {
+    Game::parse(line, 0, palette).map_err(|e| {
+        let source = NamedSource::new(format!("line {}", number), line.to_string());
+        Report::new(e).with_source_code(source)
+    })
+}
+
+/// Where `part` is in the input, given that `whole`, which it's a slice of, starts at `start`
+fn span(start: usize, whole: &str, part: &str) -> SourceSpan {
+    (start + offset(whole, part), part.len()).into()
+}
+
+/// Like [`part_1`], but reads one game at a time so the input never has to fit in memory
+pub fn part_1_streaming(input: impl BufRead) -> Result<Answer> {
+    let (palette, bag) = (Palette::classic(), part_1_bag());
+
+    let mut total = Answer(0);
+    for (i, line) in input.lines().enumerate() {
+        let game = parse_streamed_game(&line.into_diagnostic()?, i + 1, &palette)?;
+
+        if game.valid_for(&bag) {
+            total = total
+                .checked_add(game.id, DAY, "summing game ids")
+                .into_diagnostic()?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// Like [`part_2`], but reads one game at a time so the input never has to fit in memory
+pub fn part_2_streaming(input: impl BufRead) -> Result<Answer> {
+    let palette = Palette::classic();
+
+    let mut total = Answer(0);
+    for (i, line) in input.lines().enumerate() {
+        let game = parse_streamed_game(&line.into_diagnostic()?, i + 1, &palette)?;
+        let power = game.minumum_power(&palette).into_diagnostic()?;
+
+        total = total
+            .checked_add(power.0, DAY, "summing game powers")
+            .into_diagnostic()?;
+    }
+
+    Ok(total)
+}
+
+/// Holds a parsed set of games so bags can be tried against them from the runner's REPL
+pub struct Explorer {
+    games: Games,
+}
+
+impl Explorer {
+    pub const QUERIES: &'static [&'static str] = &[
+        "minimal-bag - the smallest bag every game is possible with",
+        "possible <bag> - list the games a bag allows, e.g. `possible 12 red 13 green 14 blue`",
+        "impossible <bag> - show the draw that rules out each game a bag doesn't allow",
+        "estimate - how big each game's bag could be, beyond the minimum part 2 uses",
+        "estimate-shared - the most likely single bag behind every game",
+    ];
+
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            games: Games::parse(input, &Palette::Open)?,
+        })
+    }
+
+    pub fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+        match query {
+            "minimal-bag" => Ok(self.games.minimal_bag().to_string()),
+            "possible" => {
+                let feasibility = self.games.feasibility(&parse_bag(args)?);
+                let ids = feasibility
+                    .possible
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>();
+
+                Ok(format!("{} games: {}", ids.len(), ids.join(", ")))
+            }
+            "impossible" => Ok(self
+                .games
+                .feasibility(&parse_bag(args)?)
+                .impossible
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")),
+            "estimate" => {
+                let estimator = Estimator::default();
+                let lines = estimator
+                    .games(&self.games, &Palette::Open)
+                    .map_err(|e| e.to_string())?
+                    .iter()
+                    .map(|(id, estimate)| report_estimate(&format!("Game {}", id), estimate))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(format!(
+                    "{}\n{}",
+                    report_prior(&estimator),
+                    lines.join("\n")
+                ))
+            }
+            "estimate-shared" => {
+                let estimator = Estimator::default();
+                let estimate = estimator
+                    .shared(&self.games, &Palette::Open)
+                    .map_err(|e| e.to_string())?;
+
+                Ok(format!(
+                    "{}\n{}",
+                    report_prior(&estimator),
+                    report_estimate("Every game", &estimate)?
+                ))
+            }
+            _ => Err(format!("Unknown query: {}", query)),
+        }
+    }
+}
+
+/// What's assumed before the draws are seen, which the ranges of totals lean on heavily
+fn report_prior(estimator: &Estimator) -> String {
+    format!(
+        "Assuming every bag with up to {} of each color (or the most drawn, if more) is equally \
+         likely, with ranges holding {:.0}% of the posterior:",
+        estimator.limit,
+        estimator.level * 100.0
+    )
+}
+
+fn report_estimate(name: &str, estimate: &Estimate) -> Result<String, String> {
+    let power = estimate.most_likely_power().map_err(|e| e.to_string())?;
+    let total = estimate.most_likely_total().map_err(|e| e.to_string())?;
+
+    Ok(format!(
+        "{}: most likely {} ({} cubes, power {}), expected power {:.0}, {}..={} cubes in all",
+        name,
+        estimate.most_likely,
+        total,
+        power,
+        estimate.expected_power(),
+        estimate.total_range.0,
+        estimate.total_range.1
+    ))
+}
+
+/// Reads a bag written as counts and colors, like `12 red 13 green`
+fn parse_bag(args: &[&str]) -> Result<CubeDraw, String> {
+    if args.is_empty() || !args.len().is_multiple_of(2) {
+        return Err("Expected a bag like `12 red 13 green 14 blue`".to_string());
+    }
+
+    args.chunks(2)
+        .map(|pair| {
+            let count = pair[0]
+                .parse::<u32>()
+                .map_err(|_| format!("{} isn't a count", pair[0]))?;
+
+            Ok((pair[1], count))
+        })
+        .collect::<Result<Vec<_>, String>>()
+        .map(CubeDraw::new)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cube_draw() -> Result<()> {
+        let input = "8 green, 6 blue";
+        let cd = CubeDraw::parse(input, 0, &Palette::classic())?;
+
+        assert_eq!(cd, CubeDraw::new([("blue", 6), ("green", 8)]));
+        assert_eq!(cd.count("red"), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |line| Game::parse(line, 10, &Palette::classic()).unwrap_err();
+
+        assert_eq!(
+            parse("3 blue, 4 red"),
+            ParseError::MissingPrefix {
+                span: (10, 13).into()
+            }
+        );
+        assert_eq!(
+            parse("Round 1: 3 blue"),
+            ParseError::MissingPrefix {
+                span: (10, 7).into()
+            }
+        );
+        assert_eq!(
+            parse("Game x: 3 blue"),
+            ParseError::BadGameId {
+                id: "x".to_string(),
+                span: (15, 1).into()
+            }
+        );
+        assert_eq!(
+            parse("Game 1: 3 blue;; 1 red"),
+            ParseError::EmptyDraw {
+                span: (25, 0).into()
+            }
+        );
+        assert_eq!(
+            parse("Game 1: three blue"),
+            ParseError::BadCount {
+                count: "three".to_string(),
+                span: (18, 5).into()
+            }
+        );
+        assert_eq!(
+            parse("Game 1: 3 blue, 1 red, 2 blue"),
+            ParseError::DuplicateColor {
+                color: "blue".to_string(),
+                first: (20, 4).into(),
+                again: (35, 4).into()
+            }
+        );
+        assert_eq!(
+            parse("Game 1: 3 blue, 2"),
+            ParseError::BadCubes {
+                span: (25, 2).into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_points_into_input() {
+        let input = "Game 1: 3 blue\nGame 2: 2 red, x green";
+        let report = part_1(input).unwrap_err();
+
+        let labels = report.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!(report.to_string(), "Expected a number of cubes, not `x`");
+        assert_eq!(labels.len(), 1);
+        assert_eq!((labels[0].offset(), labels[0].len()), (30, 1));
+        assert!(report.source_code().is_some());
+    }
+
+    #[test]
+    fn test_palettes() -> Result<()> {
+        let input = "Game 1: 2 red, 3 purple; 1 purple\nGame 2: 4 red, 1 green";
+
+        assert!(part_1(input).is_err());
+        assert!(CubeDraw::parse("1 purple", 0, &Palette::only(["purple"])).is_ok());
+
+        let bag = CubeDraw::new([("red", 4), ("purple", 3)]);
+        assert_eq!(part_1_with(input, &Palette::Open, &bag)?, Answer(1));
+
+        // Game 2 has no purple, which only zeroes its power if purple is in the palette
+        assert_eq!(part_2_with(input, &Palette::Open)?, Answer(6 + 4));
+        let palette = Palette::only(["red", "green", "purple"]);
+        assert_eq!(part_2_with(input, &palette)?, Answer(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate() -> Result<()> {
+        let games = Games::parse(include_str!("sample.input"), &Palette::classic())?;
+        let estimates = Estimator::default().games(&games, &Palette::classic())?;
+
+        for ((_, estimate), game) in estimates.iter().zip(&games.games) {
+            assert_eq!(estimate.most_likely, game.minimum_bag());
+            assert_eq!(
+                estimate.most_likely_power().unwrap(),
+                game.minumum_power(&Palette::classic()).unwrap()
+            );
+            assert!(estimate.expected_power() > estimate.most_likely_power().unwrap().0 as f64);
+
+            // The most likely bag's total is always in the range, and it's the smallest
+            // total there can be
+            let (low, high) = estimate.total_range;
+            assert_eq!(estimate.most_likely_total().unwrap(), low);
+            assert!(low < high);
+        }
+
+        // Seeing the same bag more often narrows down how big it is
+        let shared = Estimator::default().shared(&games, &Palette::classic())?;
+        let width = |(low, high): (u32, u32)| high - low;
+        assert!(estimates
+            .iter()
+            .all(|(_, e)| width(shared.total_range) < width(e.total_range)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_limits() -> Result<()> {
+        let input = "Game 1: 20000 red, 2 blue";
+        let games = Games::parse(input, &Palette::classic())?;
+
+        assert_eq!(
+            Estimator::default().shared(&games, &Palette::classic()),
+            Err(EstimateError::TooManyCubes {
+                color: "red".to_string(),
+                count: 20000,
+                max: Estimator::MAX_CUBES
+            })
+        );
+
+        let bag = CubeDraw::new([("red", u32::MAX), ("blue", 1)]);
+        assert_eq!(
+            bag.total(),
+            Err(Overflow::new(DAY, "totalling the cubes in a bag"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_feasibility() -> Result<()> {
+        let games = Games::parse(include_str!("sample.input"), &Palette::classic())?;
+
+        assert_eq!(
+            games.minimal_bag(),
+            CubeDraw::new([("red", 20), ("green", 13), ("blue", 15)])
+        );
+
+        let feasibility = games.feasibility(&part_1_bag());
+        assert_eq!(feasibility.possible, vec![1, 2, 5]);
+        assert_eq!(
+            feasibility.impossible,
+            vec![
+                RuledOut {
+                    game: 3,
+                    draw: 1,
+                    color: "red".to_string(),
+                    drawn: 20,
+                    in_bag: 12
+                },
+                RuledOut {
+                    game: 4,
+                    draw: 3,
+                    color: "blue".to_string(),
+                    drawn: 15,
+                    in_bag: 14
+                }
+            ]
+        );
+
+        let feasibility = games.feasibility(&games.minimal_bag());
+        assert_eq!(feasibility.possible, vec![1, 2, 3, 4, 5]);
+
+        Ok(())
+    }
+}
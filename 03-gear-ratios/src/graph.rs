@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+
+use answer::{Answer, CheckedAnswer, Overflow};
+
+use crate::{GridNumber, Schematic, DAY};
+
+/// A schematic's numbers and symbols as a bipartite graph, with an edge wherever a number
+/// touches a symbol
+#[derive(Debug)]
+pub struct Graph<'a> {
+    schematic: &'a Schematic,
+    /// Every symbol and where it is, in reading order
+    pub symbols: Vec<((usize, usize), char)>,
+    /// The positions in the schematic's `numbers` of the numbers next to each of `symbols`
+    pub symbol_edges: Vec<Vec<usize>>,
+    /// The positions in `symbols` of the symbols next to each of the schematic's numbers
+    pub number_edges: Vec<Vec<usize>>,
+}
+
+/// Part numbers joined together through the symbols they share
+#[derive(Debug, PartialEq)]
+pub struct Assembly<'a> {
+    pub numbers: Vec<&'a GridNumber>,
+    pub symbols: Vec<((usize, usize), char)>,
+}
+
+impl Assembly<'_> {
+    /// The sum of the assembly's part numbers
+    pub fn value(&self) -> Result<Answer, Overflow> {
+        self.numbers
+            .iter()
+            .map(|gn| gn.num)
+            .checked_sum(DAY, "summing an assembly's part numbers")
+    }
+}
+
+impl<'a> Graph<'a> {
+    pub fn new(schematic: &'a Schematic) -> Self {
+        let symbols = schematic.symbols().collect::<Vec<_>>();
+        let mut symbol_edges = vec![];
+        let mut number_edges = vec![vec![]; schematic.numbers.len()];
+
+        for (s, (cell, _)) in symbols.iter().enumerate() {
+            let neighbours = schematic.neighbours(*cell);
+            for n in &neighbours {
+                number_edges[*n].push(s);
+            }
+            symbol_edges.push(neighbours);
+        }
+
+        Self {
+            schematic,
+            symbols,
+            symbol_edges,
+            number_edges,
+        }
+    }
+
+    /// The connected components that have at least one number in them, ordered by their first
+    /// number. Symbols with nothing next to them aren't part of any.
+    pub fn assemblies(&self) -> Vec<Assembly<'a>> {
+        let mut seen_numbers = vec![false; self.number_edges.len()];
+        let mut seen_symbols = vec![false; self.symbols.len()];
+        let mut assemblies = vec![];
+
+        for start in 0..self.number_edges.len() {
+            if seen_numbers[start] || self.number_edges[start].is_empty() {
+                continue;
+            }
+
+            let mut numbers = vec![];
+            let mut symbols = vec![];
+            let mut queue = VecDeque::from([start]);
+            seen_numbers[start] = true;
+
+            // Alternates sides on its own: every number queued leads to its symbols, and
+            // every new symbol to its numbers
+            while let Some(n) = queue.pop_front() {
+                numbers.push(n);
+
+                for &s in &self.number_edges[n] {
+                    if seen_symbols[s] {
+                        continue;
+                    }
+                    seen_symbols[s] = true;
+                    symbols.push(s);
+
+                    for &next in &self.symbol_edges[s] {
+                        if !seen_numbers[next] {
+                            seen_numbers[next] = true;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+
+            numbers.sort_unstable();
+            symbols.sort_unstable();
+            assemblies.push(Assembly {
+                numbers: numbers
+                    .into_iter()
+                    .map(|n| &self.schematic.numbers[n])
+                    .collect(),
+                symbols: symbols.into_iter().map(|s| self.symbols[s]).collect(),
+            });
+        }
+
+        assemblies
+    }
+
+    /// The numbers that don't touch any symbol, in reading order
+    pub fn orphans(&self) -> impl Iterator<Item = &'a GridNumber> + '_ {
+        self.schematic
+            .numbers
+            .iter()
+            .zip(&self.number_edges)
+            .filter_map(|(gn, edges)| edges.is_empty().then_some(gn))
+    }
+}
@@ -0,0 +1,483 @@
+use answer::{Answer, CheckedAnswer, OrOverflow, Overflow};
+use miette::{IntoDiagnostic, Result};
+
+pub use graph::{Assembly, Graph};
+pub use notation::{Notation, Symbols};
+pub use rules::{Aggregate, GearRule, Neighbours};
+pub use schematic::{Gear, Schematic};
+
+mod graph;
+mod notation;
+pub mod render;
+mod rules;
+mod schematic;
+
+const DAY: u8 = 3;
+
+#[derive(Debug, PartialEq)]
+pub struct GridNumber {
+    /// Only ever negative when the schematic's [`Notation`] is signed
+    pub num: i64,
+    pub y: usize,
+    pub x_start: usize,
+    pub x_end: usize,
+}
+impl GridNumber {
+    fn is_part_number(&self, schematic: &Schematic) -> bool {
+        let array = &schematic.cells;
+
+        'outer: for ny in [self.y.checked_sub(1), Some(self.y), Some(self.y + 1)]
+            .iter()
+            .flatten()
+        {
+            if *ny >= array.len() {
+                continue;
+            }
+
+            let mut nx = self.x_start.saturating_sub(1);
+            while nx <= self.x_end + 1 {
+                if nx >= array[*ny].len() {
+                    continue 'outer;
+                }
+
+                if schematic.is_symbol_at((*ny, nx)) {
+                    return true;
+                }
+
+                nx += 1;
+            }
+        }
+
+        false
+    }
+
+    fn is_next_to(&self, (i, j): (usize, usize)) -> bool {
+        if self.y.abs_diff(i) > 1 {
+            return false;
+        }
+
+        if j < self.x_start.saturating_sub(1) || j > self.x_end + 1 {
+            return false;
+        }
+
+        true
+    }
+}
+
+pub fn part_1(input: &str) -> Result<Answer> {
+    Schematic::parse(input)
+        .into_diagnostic()?
+        .part_numbers()
+        .map(|gn| gn.num)
+        .checked_sum(DAY, "summing part numbers")
+        .into_diagnostic()
+}
+
+/// [`part_1`] by looking around every number for a symbol, without the index
+pub fn part_1_scan(input: &str) -> Result<Answer> {
+    let schematic = Schematic::parse(input).into_diagnostic()?;
+
+    schematic
+        .numbers
+        .iter()
+        .filter(|gn| gn.is_part_number(&schematic))
+        .map(|gn| gn.num)
+        .checked_sum(DAY, "summing part numbers")
+        .into_diagnostic()
+}
+
+fn parse_grid_numbers(input: &str, notation: Notation) -> Result<Schematic, Overflow> {
+    let array = input
+        .lines()
+        .map(|l| l.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut grid_numbers = vec![];
+    let mut index = array
+        .iter()
+        .map(|row| vec![None; row.len()])
+        .collect::<Vec<_>>();
+
+    let mut i = 0;
+    while i < array.len() {
+        let mut j = 0;
+        let mut digit_started_at: Option<usize> = None;
+
+        while j < array[i].len() {
+            let c = array[i][j];
+
+            if c.is_ascii_digit() {
+                if digit_started_at.is_none() {
+                    let signed = notation.signed
+                        && j > 0
+                        && array[i][j - 1] == '-'
+                        && (j < 2 || !array[i][j - 2].is_ascii_digit());
+
+                    digit_started_at = Some(if signed { j - 1 } else { j });
+                }
+            } else if let Some(start) = digit_started_at {
+                let grid_number = GridNumber {
+                    num: read_number(&array[i][start..j], i, start)?,
+                    y: i,
+                    x_start: start,
+                    x_end: j - 1,
+                };
+                index[i][start..j].fill(Some(grid_numbers.len()));
+                grid_numbers.push(grid_number);
+
+                digit_started_at = None;
+            };
+
+            j += 1;
+        }
+        if let Some(start) = digit_started_at {
+            let grid_number = GridNumber {
+                num: read_number(&array[i][start..j], i, start)?,
+                y: i,
+                x_start: start,
+                x_end: j - 1,
+            };
+            index[i][start..j].fill(Some(grid_numbers.len()));
+            grid_numbers.push(grid_number);
+        }
+
+        i += 1;
+    }
+    Ok(Schematic {
+        cells: array,
+        numbers: grid_numbers,
+        notation,
+        index,
+    })
+}
+
+/// The number spelled out by `digits`, which start at column `x` of row `y`. They're only
+/// ever digits and maybe a sign, so the one way this fails is a number too big for an `i64`.
+fn read_number(digits: &[char], y: usize, x: usize) -> Result<i64, Overflow> {
+    digits
+        .iter()
+        .collect::<String>()
+        .parse::<i64>()
+        .ok()
+        .or_overflow_with(DAY, || {
+            format!("reading the number at row {}, column {}", y, x)
+        })
+}
+
+pub fn part_2(input: &str) -> Result<Answer> {
+    part_2_with(input, &GearRule::default())
+}
+
+/// Sums the value of every gear, with what a gear is and what it's worth decided by `rule`
+pub fn part_2_with(input: &str, rule: &GearRule) -> Result<Answer> {
+    Schematic::parse(input)
+        .into_diagnostic()?
+        .gear_total(rule)
+        .into_diagnostic()
+}
+
+/// [`part_2`] by checking every number against every `*`, without the index
+pub fn part_2_scan(input: &str) -> Result<Answer> {
+    let Schematic {
+        cells: array,
+        numbers: grid_numbers,
+        ..
+    } = Schematic::parse(input).into_diagnostic()?;
+
+    let mut gear_ratios = vec![];
+    let mut i = 0;
+    while i < array.len() {
+        let mut j = 0;
+        while j < array[i].len() {
+            let c = array[i][j];
+            if c == '*' {
+                let neighbors = grid_numbers
+                    .iter()
+                    .filter(|gn| gn.is_next_to((i, j)))
+                    .collect::<Vec<_>>();
+
+                if neighbors.len() == 2 {
+                    // Two i64s always fit in an i128, so only the sum can overflow
+                    gear_ratios.push(i128::from(neighbors[0].num) * i128::from(neighbors[1].num))
+                }
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    gear_ratios
+        .into_iter()
+        .checked_sum(DAY, "summing gear ratios")
+        .into_diagnostic()
+}
+
+/// Holds a parsed schematic so it can be queried from the runner's REPL
+pub struct Explorer {
+    schematic: Schematic,
+}
+
+impl Explorer {
+    pub const QUERIES: &'static [&'static str] = &[
+        "gears <symbols> <exactly|at-least|at-most> <n> <product|sum|max> - count and total the \
+         gears under another rule, e.g. `gears *# at-least 2 sum`",
+        "assemblies - list the groups of part numbers joined up through shared symbols",
+        "orphans - list the numbers that don't touch any symbol",
+    ];
+
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            schematic: Schematic::parse(input).into_diagnostic()?,
+        })
+    }
+
+    pub fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+        match (query, args) {
+            ("gears", [symbols, kind, n, aggregate]) => {
+                let n = n.parse::<usize>().map_err(|e| e.to_string())?;
+                let rule = GearRule {
+                    symbols: symbols.chars().collect(),
+                    neighbours: match *kind {
+                        "exactly" => Neighbours::Exactly(n),
+                        "at-least" => Neighbours::AtLeast(n),
+                        "at-most" => Neighbours::AtMost(n),
+                        _ => {
+                            return Err(format!(
+                                "Expected exactly, at-least or at-most, not {}",
+                                kind
+                            ))
+                        }
+                    },
+                    aggregate: aggregate.parse()?,
+                };
+
+                let total = self
+                    .schematic
+                    .gear_total(&rule)
+                    .map_err(|e| e.to_string())?;
+                Ok(format!(
+                    "{} gears, totalling {}",
+                    self.schematic.gears(&rule).count(),
+                    total
+                ))
+            }
+            ("assemblies", []) => self
+                .schematic
+                .graph()
+                .assemblies()
+                .iter()
+                .map(|assembly| {
+                    let value = assembly.value().map_err(|e| e.to_string())?;
+                    let numbers = assembly
+                        .numbers
+                        .iter()
+                        .map(|gn| gn.num.to_string())
+                        .collect::<Vec<_>>();
+                    let symbols = assembly
+                        .symbols
+                        .iter()
+                        .map(|((y, x), c)| format!("{} at {},{}", c, y, x))
+                        .collect::<Vec<_>>();
+
+                    Ok(format!(
+                        "{} ({}) via {}",
+                        value,
+                        numbers.join(" + "),
+                        symbols.join(", ")
+                    ))
+                })
+                .collect::<Result<Vec<_>, String>>()
+                .map(|lines| lines.join("\n")),
+            ("orphans", []) => Ok(self
+                .schematic
+                .graph()
+                .orphans()
+                .map(|gn| format!("{} at {},{}", gn.num, gn.y, gn.x_start))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            _ => Err(format!("Unknown query: {}", query)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn test_gear_rules() {
+        let schematic = Schematic::parse(include_str!("sample.input")).unwrap();
+        let rule = |neighbours, aggregate| GearRule {
+            symbols: BTreeSet::from(['*']),
+            neighbours,
+            aggregate,
+        };
+
+        assert_eq!(
+            schematic.gear_total(&GearRule::default()),
+            Ok(Answer(467835))
+        );
+        assert_eq!(
+            schematic.gear_total(&rule(Neighbours::Exactly(2), Aggregate::Sum)),
+            Ok(Answer(467 + 35 + 755 + 598))
+        );
+        // The lone `*` next to 617 only counts once a single neighbour is allowed
+        assert_eq!(
+            schematic.gear_total(&rule(Neighbours::AtLeast(1), Aggregate::Max)),
+            Ok(Answer(467 + 617 + 755))
+        );
+        assert_eq!(
+            schematic.gear_total(&rule(Neighbours::AtMost(1), Aggregate::Product)),
+            Ok(Answer(617))
+        );
+
+        let every_symbol = GearRule {
+            symbols: "*#+$".chars().collect(),
+            ..rule(Neighbours::AtLeast(1), Aggregate::Sum)
+        };
+        // No number in the sample touches two symbols, so this is part 1
+        assert_eq!(schematic.gear_total(&every_symbol), Ok(Answer(4361)));
+    }
+
+    fn part_total(schematic: &Schematic) -> i64 {
+        let total = schematic.part_numbers().map(|gn| gn.num).sum();
+
+        // Looking around each number has to agree with the index
+        let scanned = schematic
+            .numbers
+            .iter()
+            .filter(|gn| gn.is_part_number(schematic))
+            .map(|gn| gn.num)
+            .sum();
+        assert_eq!(total, scanned);
+
+        total
+    }
+
+    #[test]
+    fn test_symbol_classes() {
+        let input = "12€.34.\n.....x.\n7#.....";
+        let read = |symbols| {
+            Schematic::parse_with(
+                input,
+                Notation {
+                    symbols,
+                    signed: false,
+                },
+            )
+            .unwrap()
+        };
+
+        assert_eq!(part_total(&read(Symbols::AsciiPunctuation)), 7);
+        assert_eq!(part_total(&read(Symbols::NonAlphanumeric)), 12 + 7);
+        assert_eq!(part_total(&read(Symbols::Only(BTreeSet::from(['x'])))), 34);
+
+        // Empty space and digits never count, even when asked for
+        let only = Symbols::Only(BTreeSet::from(['.', '1', '#']));
+        assert!(!only.contains('.') && !only.contains('1') && only.contains('#'));
+    }
+
+    #[test]
+    fn test_signed_numbers() {
+        let input = "..-12..\n...*...\n5-3....";
+        let read = |signed| {
+            Schematic::parse_with(
+                input,
+                Notation {
+                    symbols: Symbols::AsciiPunctuation,
+                    signed,
+                },
+            )
+            .unwrap()
+        };
+
+        let unsigned = read(false);
+        assert_eq!(part_total(&unsigned), 12 + 5 + 3);
+        assert_eq!(
+            unsigned.gear_total(&GearRule::default()),
+            Ok(Answer(12 * 3))
+        );
+
+        // The `-` before 12 is its sign, but the one between 5 and 3 is still a symbol
+        let signed = read(true);
+        assert_eq!(
+            signed.numbers[0],
+            GridNumber {
+                num: -12,
+                y: 0,
+                x_start: 2,
+                x_end: 4
+            }
+        );
+        assert!(!signed.is_symbol_at((0, 2)) && signed.is_symbol_at((2, 1)));
+        assert_eq!(part_total(&signed), -12 + 5 + 3);
+        assert_eq!(signed.gear_total(&GearRule::default()), Ok(Answer(-12 * 3)));
+    }
+
+    #[test]
+    fn test_oversized_number() {
+        let input = format!("..*\n{}", "1".repeat(25));
+
+        assert_eq!(
+            Schematic::parse(&input).unwrap_err(),
+            Overflow::new(DAY, "reading the number at row 1, column 0")
+        );
+        assert!(part_1(&input).is_err() && part_2_scan(&input).is_err());
+
+        // The biggest number there is still reads fine
+        let schematic = Schematic::parse(&format!("{}*", i64::MAX)).unwrap();
+        assert_eq!(schematic.numbers[0].num, i64::MAX);
+    }
+
+    #[test]
+    fn test_assemblies() {
+        let schematic = Schematic::parse("10.22..33\n..*..#..@\n.........\n5....%...").unwrap();
+        let graph = schematic.graph();
+        let assemblies = graph.assemblies();
+
+        // 10 and 22 share the `*`, and the `%` touches nothing so isn't in any
+        assert_eq!(assemblies.len(), 2);
+        assert_eq!(assemblies[0].symbols, vec![((1, 2), '*'), ((1, 5), '#')]);
+        assert_eq!(assemblies[0].value(), Ok(Answer(10 + 22)));
+        assert_eq!(assemblies[1].value(), Ok(Answer(33)));
+
+        let orphans = graph.orphans().map(|gn| gn.num).collect::<Vec<_>>();
+        assert_eq!(orphans, vec![5]);
+    }
+
+    #[test]
+    fn test_render() {
+        use render::Role;
+
+        let input = include_str!("sample.input");
+        let schematic = Schematic::parse(input).unwrap();
+        let rule = GearRule::default();
+
+        let roles = render::roles(&schematic, &rule);
+        assert_eq!(roles[0][0], Role::PartNumber);
+        assert_eq!(roles[0][5], Role::NonPartNumber);
+        assert_eq!(roles[1][3], Role::Gear);
+        assert_eq!(roles[4][3], Role::NonGear);
+        assert_eq!(roles[3][6], Role::Symbol);
+        assert_eq!(roles[0][3], Role::Empty);
+
+        // Taking the colors back out leaves the schematic as it was
+        let ansi = render::ansi(&schematic, &rule);
+        let mut plain = String::new();
+        let mut rest = ansi.as_str();
+        while let Some(start) = rest.find('\x1b') {
+            plain.push_str(&rest[..start]);
+            rest = &rest[start + rest[start..].find('m').unwrap() + 1..];
+        }
+        plain.push_str(rest);
+        assert_eq!(plain, input);
+
+        let html = render::html(&schematic, &rule);
+        assert!(html.contains(
+            "title=\"114&#10;y: 0&#10;x_start: 5&#10;x_end: 7&#10;adjacent: none\">114</span>"
+        ));
+        assert!(html.contains("title=\"* at 1,3&#10;adjacent: 467, 35&#10;gear worth 16345\">"));
+    }
+}
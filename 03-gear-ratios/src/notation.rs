@@ -0,0 +1,38 @@
+use std::collections::BTreeSet;
+
+/// How a schematic is written: which characters are symbols, and whether numbers can be
+/// negative
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Notation {
+    pub symbols: Symbols,
+    /// Reads a `-` just before a number as its sign rather than a symbol. A `-` straight after
+    /// another number still separates the two, so `5-3` is `5`, a symbol and `3`.
+    pub signed: bool,
+}
+
+/// Which characters count as symbols. A `.` is always empty space, and a digit is always part
+/// of a number, whatever the class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Symbols {
+    /// What the puzzle uses: ASCII punctuation
+    #[default]
+    AsciiPunctuation,
+    /// Anything but letters, digits and whitespace, Unicode included
+    NonAlphanumeric,
+    /// Only these characters, which can be letters used as markers
+    Only(BTreeSet<char>),
+}
+
+impl Symbols {
+    pub fn contains(&self, c: char) -> bool {
+        if c == '.' || c.is_ascii_digit() {
+            return false;
+        }
+
+        match self {
+            Symbols::AsciiPunctuation => c.is_ascii_punctuation(),
+            Symbols::NonAlphanumeric => !c.is_alphanumeric() && !c.is_whitespace(),
+            Symbols::Only(symbols) => symbols.contains(&c),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::{GearRule, Graph, Schematic};
+
+/// What a cell of the schematic is drawn as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Empty,
+    PartNumber,
+    NonPartNumber,
+    Gear,
+    /// One of the rule's gear symbols without the right numbers next to it
+    NonGear,
+    Symbol,
+}
+
+impl Role {
+    fn ansi(self) -> Option<&'static str> {
+        match self {
+            Role::PartNumber => Some("\x1b[32m"),
+            Role::NonPartNumber => Some("\x1b[31m"),
+            Role::Gear => Some("\x1b[1;33m"),
+            Role::NonGear => Some("\x1b[35m"),
+            Role::Symbol => Some("\x1b[1m"),
+            Role::Empty => None,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Role::Empty => "empty",
+            Role::PartNumber => "part",
+            Role::NonPartNumber => "non-part",
+            Role::Gear => "gear",
+            Role::NonGear => "non-gear",
+            Role::Symbol => "symbol",
+        }
+    }
+}
+
+/// The role of every cell, with gears decided by `rule`
+pub fn roles(schematic: &Schematic, rule: &GearRule) -> Vec<Vec<Role>> {
+    let graph = schematic.graph();
+    let mut roles = schematic
+        .cells
+        .iter()
+        .map(|row| vec![Role::Empty; row.len()])
+        .collect::<Vec<_>>();
+
+    for (gn, edges) in schematic.numbers.iter().zip(&graph.number_edges) {
+        let role = if edges.is_empty() {
+            Role::NonPartNumber
+        } else {
+            Role::PartNumber
+        };
+        roles[gn.y][gn.x_start..=gn.x_end].fill(role);
+    }
+
+    for (((y, x), symbol), edges) in graph.symbols.iter().zip(&graph.symbol_edges) {
+        roles[*y][*x] = if rule.allows(*symbol, edges.len()) {
+            Role::Gear
+        } else if rule.symbols.contains(symbol) {
+            Role::NonGear
+        } else {
+            Role::Symbol
+        };
+    }
+
+    roles
+}
+
+/// The schematic with part numbers in green, other numbers in red, gears in yellow and the
+/// gear symbols that aren't gears in magenta
+pub fn ansi(schematic: &Schematic, rule: &GearRule) -> String {
+    let mut out = String::new();
+
+    for (row, roles) in schematic.cells.iter().zip(roles(schematic, rule)) {
+        let mut current = None;
+        for (c, role) in row.iter().zip(roles) {
+            // Only change color where a run of one role ends, so a number is one span
+            if current != role.ansi() {
+                if current.is_some() {
+                    out.push_str("\x1b[0m");
+                }
+                out.extend(role.ansi());
+                current = role.ansi();
+            }
+            out.push(*c);
+        }
+        if current.is_some() {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// The schematic as a standalone HTML page, colored like [`ansi`]. Hovering over a number
+/// shows where it is and the symbols next to it, and hovering over a symbol the numbers next
+/// to it.
+pub fn html(schematic: &Schematic, rule: &GearRule) -> String {
+    let graph = schematic.graph();
+    let roles = roles(schematic, rule);
+    let symbol_at = graph
+        .symbols
+        .iter()
+        .enumerate()
+        .map(|(s, (cell, _))| (*cell, s))
+        .collect::<HashMap<_, _>>();
+
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<style>\n\
+         .part { color: green; }\n.non-part { color: red; }\n\
+         .gear { color: goldenrod; font-weight: bold; }\n.non-gear { color: magenta; }\n\
+         .symbol { font-weight: bold; }\n</style>\n</head>\n<body>\n<pre>\n",
+    );
+
+    for (y, row) in schematic.cells.iter().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let role = roles[y][x];
+
+            if let Some(i) = schematic.index[y][x] {
+                let gn = &schematic.numbers[i];
+                let symbols = graph.number_edges[i]
+                    .iter()
+                    .map(|s| describe_symbol(&graph, *s))
+                    .collect::<Vec<_>>();
+                let title = format!(
+                    "{}\ny: {}\nx_start: {}\nx_end: {}\nadjacent: {}",
+                    gn.num,
+                    gn.y,
+                    gn.x_start,
+                    gn.x_end,
+                    list(&symbols)
+                );
+
+                span(&mut out, role, &title, &row[gn.x_start..=gn.x_end]);
+                x = gn.x_end + 1;
+            } else if role == Role::Empty {
+                escape(&mut out, &row[x..=x]);
+                x += 1;
+            } else {
+                let s = symbol_at[&(y, x)];
+                let numbers = graph.symbol_edges[s]
+                    .iter()
+                    .map(|n| schematic.numbers[*n].num.to_string())
+                    .collect::<Vec<_>>();
+                let mut title = format!(
+                    "{}\nadjacent: {}",
+                    describe_symbol(&graph, s),
+                    list(&numbers)
+                );
+                if role == Role::Gear {
+                    let gear = graph.symbol_edges[s]
+                        .iter()
+                        .map(|n| &schematic.numbers[*n])
+                        .collect::<Vec<_>>();
+                    match rule.value(&gear) {
+                        Ok(value) => write!(title, "\ngear worth {}", value),
+                        Err(e) => write!(title, "\n{}", e),
+                    }
+                    .unwrap();
+                }
+
+                span(&mut out, role, &title, &row[x..=x]);
+                x += 1;
+            }
+        }
+        out.push('\n');
+    }
+
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+fn describe_symbol(graph: &Graph, s: usize) -> String {
+    let ((y, x), c) = graph.symbols[s];
+
+    format!("{} at {},{}", c, y, x)
+}
+
+fn list(items: &[String]) -> String {
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+fn span(out: &mut String, role: Role, title: &str, text: &[char]) {
+    write!(out, "<span class=\"{}\" title=\"", role.class()).unwrap();
+    escape(out, &title.chars().collect::<Vec<_>>());
+    out.push_str("\">");
+    escape(out, text);
+    out.push_str("</span>");
+}
+
+fn escape(out: &mut String, text: &[char]) {
+    for c in text {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\n' => out.push_str("&#10;"),
+            _ => out.push(*c),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use answer::{Answer, CheckedAnswer, Overflow};
+
+use crate::{GridNumber, DAY};
+
+/// What makes a symbol a gear, and what a gear is worth
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: BTreeSet<char>,
+    pub neighbours: Neighbours,
+    pub aggregate: Aggregate,
+}
+
+impl Default for GearRule {
+    /// The puzzle's rule: a `*` next to exactly two numbers, worth their product
+    fn default() -> Self {
+        Self {
+            symbols: BTreeSet::from(['*']),
+            neighbours: Neighbours::Exactly(2),
+            aggregate: Aggregate::Product,
+        }
+    }
+}
+
+impl GearRule {
+    /// Whether a symbol with `count` numbers next to it is a gear. One with none never is,
+    /// whatever the rule, as there'd be nothing to aggregate.
+    pub fn allows(&self, symbol: char, count: usize) -> bool {
+        count > 0 && self.symbols.contains(&symbol) && self.neighbours.allows(count)
+    }
+
+    pub fn value(&self, numbers: &[&GridNumber]) -> Result<Answer, Overflow> {
+        self.aggregate.apply(numbers)
+    }
+}
+
+/// How many numbers a gear has to have next to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbours {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Neighbours {
+    pub fn allows(&self, count: usize) -> bool {
+        match *self {
+            Neighbours::Exactly(n) => count == n,
+            Neighbours::AtLeast(n) => count >= n,
+            Neighbours::AtMost(n) => count <= n,
+        }
+    }
+}
+
+/// How a gear's numbers combine into its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    pub fn apply(&self, numbers: &[&GridNumber]) -> Result<Answer, Overflow> {
+        let nums = numbers.iter().map(|gn| gn.num);
+
+        match self {
+            Aggregate::Product => nums.checked_product(DAY, "multiplying a gear's numbers"),
+            Aggregate::Sum => nums.checked_sum(DAY, "summing a gear's numbers"),
+            Aggregate::Max => Ok(Answer(nums.max().unwrap_or(0).into())),
+        }
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Aggregate::Product),
+            "sum" => Ok(Aggregate::Sum),
+            "max" => Ok(Aggregate::Max),
+            _ => Err(format!("Expected product, sum or max, not {}", s)),
+        }
+    }
+}
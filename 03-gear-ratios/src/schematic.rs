@@ -0,0 +1,126 @@
+use answer::{Answer, Overflow};
+
+use crate::{GearRule, Graph, GridNumber, Notation, DAY};
+
+/// A parsed engine schematic, with every cell a number covers pointing back at that number so
+/// the numbers around any cell can be found without looking through all of them
+#[derive(Debug)]
+pub struct Schematic {
+    pub cells: Vec<Vec<char>>,
+    pub numbers: Vec<GridNumber>,
+    pub notation: Notation,
+    /// The position in `numbers` of the number covering each cell, if any
+    pub(crate) index: Vec<Vec<Option<usize>>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self, Overflow> {
+        Self::parse_with(input, Notation::default())
+    }
+
+    pub fn parse_with(input: &str, notation: Notation) -> Result<Self, Overflow> {
+        crate::parse_grid_numbers(input, notation)
+    }
+
+    pub fn number_at(&self, (y, x): (usize, usize)) -> Option<&GridNumber> {
+        let i = (*self.index.get(y)?.get(x)?)?;
+
+        Some(&self.numbers[i])
+    }
+
+    /// The positions in `numbers` of the numbers touching a cell, diagonals included, each
+    /// once and in reading order
+    pub fn neighbours(&self, (y, x): (usize, usize)) -> Vec<usize> {
+        let mut found = vec![];
+
+        for ny in [y.checked_sub(1), Some(y), Some(y + 1)]
+            .into_iter()
+            .flatten()
+        {
+            let Some(row) = self.index.get(ny) else {
+                continue;
+            };
+
+            for nx in x.saturating_sub(1)..=x + 1 {
+                // A number covers a run of cells in one row, so it can only repeat straight
+                // after itself
+                if let Some(Some(i)) = row.get(nx) {
+                    if found.last() != Some(i) {
+                        found.push(*i);
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Whether there's a symbol at a cell. A `-` that's the sign of a number isn't one.
+    pub fn is_symbol_at(&self, (y, x): (usize, usize)) -> bool {
+        match self.cells.get(y).and_then(|row| row.get(x)) {
+            Some(c) => self.notation.symbols.contains(*c) && self.index[y][x].is_none(),
+            None => false,
+        }
+    }
+
+    /// Every symbol in the schematic along with where it is, in reading order
+    pub fn symbols(&self) -> impl Iterator<Item = ((usize, usize), char)> + '_ {
+        self.cells.iter().enumerate().flat_map(move |(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(move |(x, _)| self.is_symbol_at((y, *x)))
+                .map(move |(x, c)| ((y, x), *c))
+        })
+    }
+
+    /// The numbers next to at least one symbol, in reading order
+    pub fn part_numbers(&self) -> impl Iterator<Item = &GridNumber> {
+        let mut is_part = vec![false; self.numbers.len()];
+        for (cell, _) in self.symbols() {
+            for i in self.neighbours(cell) {
+                is_part[i] = true;
+            }
+        }
+
+        self.numbers
+            .iter()
+            .zip(is_part)
+            .filter_map(|(gn, is_part)| is_part.then_some(gn))
+    }
+
+    /// The symbols `rule` makes gears of, with the numbers next to each, in reading order
+    pub fn gears<'a>(&'a self, rule: &'a GearRule) -> impl Iterator<Item = Gear<'a>> {
+        self.symbols().filter_map(|(cell, symbol)| {
+            let neighbours = self.neighbours(cell);
+            if !rule.allows(symbol, neighbours.len()) {
+                return None;
+            }
+
+            Some(Gear {
+                cell,
+                symbol,
+                numbers: neighbours.into_iter().map(|i| &self.numbers[i]).collect(),
+            })
+        })
+    }
+
+    pub fn graph(&self) -> Graph<'_> {
+        Graph::new(self)
+    }
+
+    pub fn gear_total(&self, rule: &GearRule) -> Result<Answer, Overflow> {
+        self.gears(rule)
+            .map(|gear| rule.value(&gear.numbers))
+            .try_fold(Answer(0), |total, value| {
+                total.checked_add(value?.0, DAY, "summing gear ratios")
+            })
+    }
+}
+
+/// A symbol that counts as a gear under some [`GearRule`]
+#[derive(Debug, PartialEq)]
+pub struct Gear<'a> {
+    pub cell: (usize, usize),
+    pub symbol: char,
+    pub numbers: Vec<&'a GridNumber>,
+}
@@ -0,0 +1,158 @@
+use std::fmt::Write;
+
+use answer::{Answer, CheckedAnswer, Overflow};
+
+use crate::{copy_counts, Cards, Overrun, ScratchcardError, DAY};
+
+/// Where every copy of every card in part 2 came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    pub cards: Vec<CardCascade>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardCascade {
+    pub id: u32,
+    /// A card after the last one, only there for [`Overrun::Extend`]'s copies to be of. There's
+    /// no original of it.
+    pub blank: bool,
+    /// The original, if there is one, and every copy won
+    pub copies: u64,
+    /// The earlier cards that won copies of this one, and how many each won
+    pub won_from: Vec<(u32, u64)>,
+    /// How many cards the original leads to in all, itself included, through the copies it
+    /// wins, the copies those win, and so on. Every card traces back to exactly one original,
+    /// so these add up to part 2's answer.
+    pub contribution: u64,
+}
+
+impl Cascade {
+    pub fn trace(input: &str, overrun: Overrun) -> Result<Self, ScratchcardError> {
+        let cards = Cards::parse(input)?.cards;
+
+        let mut won_from = vec![vec![]; cards.len()];
+        let mut wins = vec![vec![]; cards.len()];
+        let copies = copy_counts(&cards, overrun, |winner, won, copies| {
+            if won_from.len() <= won {
+                won_from.resize(won + 1, vec![]);
+                wins.resize(won + 1, vec![]);
+            }
+            won_from[won].push((winner as u32 + 1, copies));
+            wins[winner].push(won);
+        })?;
+        won_from.resize(copies.len(), vec![]);
+        wins.resize(copies.len(), vec![]);
+
+        // A copy of a card leads to itself and whatever one copy of each card it wins leads
+        // to, and it only ever wins later cards, so working backwards has those ready
+        let mut per_copy = vec![0_u64; copies.len()];
+        for i in (0..copies.len()).rev() {
+            per_copy[i] = wins[i]
+                .iter()
+                .try_fold(1_u64, |sum, j| sum.checked_add(per_copy[*j]))
+                .ok_or_else(|| Overflow::new(DAY, format!("tracing card {}", i + 1)))?;
+        }
+
+        Ok(Self {
+            cards: copies
+                .into_iter()
+                .zip(won_from)
+                .enumerate()
+                .map(|(i, (copies, won_from))| {
+                    let blank = i >= cards.len();
+                    CardCascade {
+                        id: i as u32 + 1,
+                        blank,
+                        copies,
+                        won_from,
+                        contribution: if blank { 0 } else { per_copy[i] },
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    pub fn total(&self) -> Result<Answer, Overflow> {
+        self.cards
+            .iter()
+            .map(|c| c.copies)
+            .checked_sum(DAY, "summing card copies")
+    }
+
+    /// A row for every card, with `by_contribution` putting the originals that lead to the most
+    /// cards first
+    pub fn table(&self, by_contribution: bool) -> String {
+        let mut cards = self.cards.iter().collect::<Vec<_>>();
+        if by_contribution {
+            cards.sort_by_key(|c| std::cmp::Reverse(c.contribution));
+        }
+
+        let mut out = format!(
+            "{:>8} {:>12} {:>12}  won from\n",
+            "card", "copies", "contributes"
+        );
+        for card in cards {
+            let id = if card.blank {
+                format!("{}*", card.id)
+            } else {
+                card.id.to_string()
+            };
+            let won_from = card
+                .won_from
+                .iter()
+                .map(|(winner, copies)| format!("{}x{}", winner, copies))
+                .collect::<Vec<_>>();
+
+            writeln!(
+                out,
+                "{:>8} {:>12} {:>12}  {}",
+                id,
+                card.copies,
+                card.contribution,
+                if won_from.is_empty() {
+                    "-".to_string()
+                } else {
+                    won_from.join(" ")
+                }
+            )
+            .unwrap();
+        }
+        if self.cards.iter().any(|c| c.blank) {
+            out.push_str("* a blank card past the last one\n");
+        }
+
+        out
+    }
+
+    /// The cascade as a Graphviz graph, with an edge from each card to every card it won
+    /// copies of, labelled with how many
+    pub fn dot(&self) -> String {
+        let mut out = String::from("digraph cascade {\n");
+
+        for card in &self.cards {
+            writeln!(
+                out,
+                "    card{} [label=\"Card {}\\ncopies: {}\\ncontributes: {}\"{}];",
+                card.id,
+                card.id,
+                card.copies,
+                card.contribution,
+                if card.blank { ", style=dashed" } else { "" }
+            )
+            .unwrap();
+        }
+        for card in &self.cards {
+            for (winner, copies) in &card.won_from {
+                writeln!(
+                    out,
+                    "    card{} -> card{} [label=\"{}\"];",
+                    winner, card.id, copies
+                )
+                .unwrap();
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use answer::Overflow;
+
+/// A card that couldn't be read. The line and column both count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingPrefix,
+    BadId(String),
+    /// Part 2 finds the cards a card wins by position, so the ids have to count up from 1
+    /// with one card to a line
+    OutOfSequence {
+        expected: u32,
+        found: u32,
+    },
+    MissingColon,
+    MissingBar,
+    BadNumber(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}, column {}: ", self.line, self.column)?;
+
+        match &self.kind {
+            ParseErrorKind::MissingPrefix => write!(f, "expected the line to start with `Card`"),
+            ParseErrorKind::BadId(id) => write!(f, "{:?} isn't a card id", id),
+            ParseErrorKind::OutOfSequence { expected, found } => write!(
+                f,
+                "expected card {} but found card {}, cards have to be numbered in order from 1",
+                expected, found
+            ),
+            ParseErrorKind::MissingColon => write!(f, "expected a `:` after the card id"),
+            ParseErrorKind::MissingBar => {
+                write!(f, "expected a `|` between the winning numbers and ours")
+            }
+            ParseErrorKind::BadNumber(n) => write!(f, "{:?} isn't a number", n),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Something odd about a card that doesn't stop it being scored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub line: usize,
+    pub column: usize,
+    pub kind: WarningKind,
+}
+
+/// The warnings raised reading a table of cards. Only the first [`Warnings::KEPT`] are held on
+/// to and the rest just counted, so a stream of cards full of them can't use up memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Warnings {
+    pub kept: Vec<Warning>,
+    pub dropped: usize,
+}
+
+impl Warnings {
+    pub const KEPT: usize = 100;
+
+    pub(crate) fn push(&mut self, warning: Warning) {
+        if self.kept.len() < Self::KEPT {
+            self.kept.push(warning);
+        } else {
+            self.dropped = self.dropped.saturating_add(1);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kept.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    /// Only counts once towards the card's matches
+    DuplicateWinning(u32),
+    /// Only counts once towards the card's matches
+    DuplicateOurs(u32),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}, column {}: ", self.line, self.column)?;
+
+        match self.kind {
+            WarningKind::DuplicateWinning(n) => write!(f, "{} is already a winning number", n),
+            WarningKind::DuplicateOurs(n) => write!(f, "{} is already one of our numbers", n),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScratchcardError {
+    Parse(ParseError),
+    /// A card won copies of more cards than come after it
+    PastLastCard {
+        id: u32,
+        wins: usize,
+        remaining: usize,
+    },
+    Overflow(Overflow),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for ScratchcardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScratchcardError::Parse(e) => e.fmt(f),
+            ScratchcardError::PastLastCard {
+                id,
+                wins,
+                remaining,
+            } => write!(
+                f,
+                "Card {} wins copies of the next {} cards, but only {} come after it",
+                id, wins, remaining
+            ),
+            ScratchcardError::Overflow(e) => e.fmt(f),
+            ScratchcardError::Io(e) => write!(f, "Failed to read input: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ScratchcardError {}
+
+impl From<ParseError> for ScratchcardError {
+    fn from(e: ParseError) -> Self {
+        ScratchcardError::Parse(e)
+    }
+}
+
+impl From<Overflow> for ScratchcardError {
+    fn from(e: Overflow) -> Self {
+        ScratchcardError::Overflow(e)
+    }
+}
+
+impl From<std::io::Error> for ScratchcardError {
+    fn from(e: std::io::Error) -> Self {
+        ScratchcardError::Io(e)
+    }
+}
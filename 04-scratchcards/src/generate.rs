@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use answer::XorShift;
+
+/// A table of `count` cards laid out like the puzzle's, with ten winning numbers and twenty
+/// five of ours between 1 and 99, for timing inputs far bigger than it. Six in ten cards match
+/// nothing and the rest one or two numbers, so part 2's copies never get out of hand. The same
+/// `seed` always makes the same cards.
+pub fn cards(seed: u64, count: usize) -> String {
+    let mut rng = XorShift::new(seed);
+    let width = count.to_string().len();
+    let mut out = String::new();
+
+    for id in 1..=count {
+        // The front of a shuffle of every number, the first ten winning and the rest ours
+        let mut pool = (1..=99).collect::<Vec<u32>>();
+        for i in 0..35 {
+            let j = i + rng.below(pool.len() - i);
+            pool.swap(i, j);
+        }
+        let (winning, rest) = pool.split_at(10);
+
+        let matches = match rng.below(10) {
+            0..=5 => 0,
+            6 | 7 => 1,
+            _ => 2,
+        };
+        let ours = winning[..matches].iter().chain(&rest[..25 - matches]);
+
+        write!(out, "Card {:>width$}:", id).unwrap();
+        for n in winning {
+            write!(out, " {:>2}", n).unwrap();
+        }
+        out.push_str(" |");
+        for n in ours {
+            write!(out, " {:>2}", n).unwrap();
+        }
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,649 @@
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+use answer::{offset, Answer, CheckedAnswer, Overflow};
+
+use numbers::{NumberSet, Numbers};
+
+pub use cascade::{CardCascade, Cascade};
+pub use error::{ParseError, ParseErrorKind, ScratchcardError, Warning, WarningKind, Warnings};
+
+mod cascade;
+mod error;
+pub mod generate;
+mod numbers;
+
+const DAY: u8 = 4;
+
+struct Card {
+    id: u32,
+    /// How many of our numbers are winning numbers. It's all either part needs, so the numbers
+    /// themselves aren't kept.
+    matches: usize,
+}
+
+impl Card {
+    /// Reads the card on line `number`, counting from 1, noting any repeated numbers in
+    /// `warnings`
+    fn parse(line: &str, number: usize, warnings: &mut Warnings) -> Result<Self, ParseError> {
+        Self::parse_as::<Numbers>(line, number, warnings)
+    }
+
+    /// Like [`Card::parse`], with each side's numbers read into an `S` to be matched
+    fn parse_as<S: NumberSet>(
+        line: &str,
+        number: usize,
+        warnings: &mut Warnings,
+    ) -> Result<Self, ParseError> {
+        let error = |offset, kind| ParseError {
+            line: number,
+            column: column(line, offset),
+            kind,
+        };
+
+        let rest = line
+            .strip_prefix("Card")
+            .ok_or_else(|| error(0, ParseErrorKind::MissingPrefix))?;
+        let (id_part, number_part) = rest
+            .split_once(':')
+            .ok_or_else(|| error(line.len(), ParseErrorKind::MissingColon))?;
+
+        let id_text = id_part.trim();
+        let id_offset = offset(line, id_text);
+        let id = id_text
+            .parse::<u32>()
+            .map_err(|_| error(id_offset, ParseErrorKind::BadId(id_text.to_string())))?;
+        if usize::try_from(id) != Ok(number) {
+            return Err(error(
+                id_offset,
+                ParseErrorKind::OutOfSequence {
+                    expected: number as u32,
+                    found: id,
+                },
+            ));
+        }
+
+        let (winning_part, ours_part) = number_part
+            .split_once('|')
+            .ok_or_else(|| error(line.len(), ParseErrorKind::MissingBar))?;
+
+        let mut read = |part, duplicate: fn(u32) -> WarningKind| {
+            let mut numbers = S::default();
+            for word in str::split_whitespace(part) {
+                let at = offset(line, word);
+                let n = word
+                    .parse::<u32>()
+                    .map_err(|_| error(at, ParseErrorKind::BadNumber(word.to_string())))?;
+
+                if !numbers.insert(n) {
+                    warnings.push(Warning {
+                        line: number,
+                        column: column(line, at),
+                        kind: duplicate(n),
+                    });
+                }
+            }
+
+            Ok(numbers)
+        };
+
+        let winning = read(winning_part, WarningKind::DuplicateWinning)?;
+        let ours = read(ours_part, WarningKind::DuplicateOurs)?;
+
+        Ok(Self {
+            id,
+            matches: winning.matches(&ours),
+        })
+    }
+
+    /// Worked out as wide as an answer goes, so it takes a card matching 128 numbers to
+    /// overflow
+    fn points(&self) -> Result<i128, Overflow> {
+        let Some(doublings) = self.matches.checked_sub(1) else {
+            return Ok(0);
+        };
+
+        u32::try_from(doublings)
+            .ok()
+            .and_then(|d| 2_i128.checked_pow(d))
+            .ok_or_else(|| {
+                Overflow::new(
+                    DAY,
+                    format!("scoring card {}, which matches {}", self.id, self.matches),
+                )
+            })
+    }
+}
+
+/// Every card in an input, along with anything odd about them that didn't stop them being read
+pub struct Cards {
+    cards: Vec<Card>,
+    pub warnings: Warnings,
+}
+
+impl Cards {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_as::<Numbers>(input)
+    }
+
+    fn parse_as<S: NumberSet>(input: &str) -> Result<Self, ParseError> {
+        let mut warnings = Warnings::default();
+        let cards = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Card::parse_as::<S>(line, i + 1, &mut warnings))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { cards, warnings })
+    }
+}
+
+/// The column, counting from 1, of the character at byte `offset` of `line`
+fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+/// An answer along with the warnings raised reading the cards it came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scored {
+    pub total: Answer,
+    pub warnings: Warnings,
+}
+
+pub fn part_1(input: &str) -> Result<Answer, ScratchcardError> {
+    Ok(part_1_scored(input)?.total)
+}
+
+/// Like [`part_1`], but keeps the warnings
+pub fn part_1_scored(input: &str) -> Result<Scored, ScratchcardError> {
+    part_1_as::<Numbers>(input)
+}
+
+/// Part 1 matching each card's numbers with a `HashSet` a side, the way it used to, which
+/// is a good deal slower than the bitset [`part_1`] uses
+pub fn part_1_hashset(input: &str) -> Result<Answer, ScratchcardError> {
+    Ok(part_1_as::<HashSet<u32>>(input)?.total)
+}
+
+fn part_1_as<S: NumberSet>(input: &str) -> Result<Scored, ScratchcardError> {
+    let Cards { cards, warnings } = Cards::parse_as::<S>(input)?;
+    let points = cards
+        .iter()
+        .map(|c| c.points())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Scored {
+        total: points.into_iter().checked_sum(DAY, "summing card points")?,
+        warnings,
+    })
+}
+
+pub fn part_2(input: &str) -> Result<Answer, ScratchcardError> {
+    Ok(part_2_with(input, Overrun::default())?.total)
+}
+
+/// What happens to the copies a card wins of cards past the end of the table. The puzzle
+/// promises it never happens, so this only matters for other inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overrun {
+    /// Drop them, only copying the cards there are
+    #[default]
+    Clamp,
+    /// Stop at the first card that wins past the end, naming it
+    Error,
+    /// Count them as copies of blank cards after the last one, which win nothing more
+    Extend,
+}
+
+/// Like [`part_2`], with `overrun` deciding what happens to wins past the last card, and
+/// keeping the warnings
+pub fn part_2_with(input: &str, overrun: Overrun) -> Result<Scored, ScratchcardError> {
+    part_2_as::<Numbers>(input, overrun)
+}
+
+/// Part 2 matching each card's numbers with a `HashSet` a side, like [`part_1_hashset`]
+pub fn part_2_hashset(input: &str) -> Result<Answer, ScratchcardError> {
+    Ok(part_2_as::<HashSet<u32>>(input, Overrun::default())?.total)
+}
+
+fn part_2_as<S: NumberSet>(input: &str, overrun: Overrun) -> Result<Scored, ScratchcardError> {
+    let Cards { cards, warnings } = Cards::parse_as::<S>(input)?;
+
+    Ok(Scored {
+        total: copy_counts(&cards, overrun, |_, _, _| {})?
+            .into_iter()
+            .checked_sum(DAY, "summing card copies")?,
+        warnings,
+    })
+}
+
+/// How many copies of each card end up being won, blank cards from [`Overrun::Extend`]
+/// included. `won` is told every time a card's copies win copies of a later card, with the
+/// positions of both and how many copies were won.
+fn copy_counts(
+    cards: &[Card],
+    overrun: Overrun,
+    mut won: impl FnMut(usize, usize, u64),
+) -> Result<Vec<u64>, ScratchcardError> {
+    let mut copy_count = vec![1_u64; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let copies = copy_count[i];
+        let winning_numbers = card.matches;
+
+        if i + winning_numbers >= copy_count.len() {
+            match overrun {
+                Overrun::Clamp => {}
+                Overrun::Error => {
+                    return Err(ScratchcardError::PastLastCard {
+                        id: card.id,
+                        wins: winning_numbers,
+                        remaining: cards.len() - i - 1,
+                    })
+                }
+                Overrun::Extend => copy_count.resize(i + winning_numbers + 1, 0),
+            }
+        }
+
+        for j in 1..=winning_numbers.min(copy_count.len() - i - 1) {
+            copy_count[j + i] = copies
+                .checked_add(copy_count[j + i])
+                .ok_or_else(|| overflowed_copying(card.id, j + i + 1))?;
+            won(i, j + i, copies);
+        }
+    }
+
+    Ok(copy_count)
+}
+
+/// Like [`part_1`], but reads one card at a time so the input never has to fit in memory
+pub fn part_1_streaming(input: impl BufRead) -> Result<Answer, ScratchcardError> {
+    Ok(part_1_streaming_scored(input)?.total)
+}
+
+/// Like [`part_1_streaming`], but keeps the warnings
+pub fn part_1_streaming_scored(input: impl BufRead) -> Result<Scored, ScratchcardError> {
+    let mut total = Answer(0);
+    let mut warnings = Warnings::default();
+    for (i, line) in input.lines().enumerate() {
+        let points = Card::parse(&line?, i + 1, &mut warnings)?.points()?;
+
+        total = total.checked_add(points, DAY, "summing card points")?;
+    }
+
+    Ok(Scored { total, warnings })
+}
+
+/// Like [`part_2`], but reads one card at a time.
+///
+/// A card can only win copies of the cards just after it, so rather than a count for every
+/// card this only keeps the copies already won of the next few cards, which is never more than
+/// the most numbers any one card has matched.
+pub fn part_2_streaming(input: impl BufRead) -> Result<Answer, ScratchcardError> {
+    Ok(part_2_streaming_with(input, Overrun::default())?.total)
+}
+
+/// Names both cards, as the one whose count overflows can be well after the one that won it
+fn overflowed_copying(winner: u32, won: usize) -> Overflow {
+    Overflow::new(
+        DAY,
+        format!("adding card {}'s copies to card {}", winner, won),
+    )
+}
+
+/// Copies won of a card that hasn't been read yet
+#[derive(Debug, Clone, Copy)]
+struct Won {
+    copies: u64,
+    /// The id of the first card to win any, and how many cards it won. Wins always cover the
+    /// cards straight after the winner, so once the input runs out, the first card to win past
+    /// the end is the one that first won the card just after the last.
+    first_winner: (u32, usize),
+}
+
+/// Like [`part_2_with`], but reads one card at a time. Whether any wins ran past the last card
+/// is only known once the input runs out.
+pub fn part_2_streaming_with(
+    input: impl BufRead,
+    overrun: Overrun,
+) -> Result<Scored, ScratchcardError> {
+    let mut pending = VecDeque::<Won>::new();
+    let mut total = Answer(0);
+    let mut count = 0;
+    let mut warnings = Warnings::default();
+
+    for (i, line) in input.lines().enumerate() {
+        let card = Card::parse(&line?, i + 1, &mut warnings)?;
+        let copies = pending
+            .pop_front()
+            .map_or(0, |won| won.copies)
+            .checked_add(1)
+            .ok_or_else(|| Overflow::new(DAY, format!("counting copies of card {}", card.id)))?;
+        let winning_numbers = card.matches;
+
+        if pending.len() < winning_numbers {
+            pending.resize(
+                winning_numbers,
+                Won {
+                    copies: 0,
+                    first_winner: (card.id, winning_numbers),
+                },
+            );
+        }
+        for (j, won) in pending.iter_mut().take(winning_numbers).enumerate() {
+            won.copies = won
+                .copies
+                .checked_add(copies)
+                .ok_or_else(|| overflowed_copying(card.id, card.id as usize + j + 1))?;
+        }
+
+        total = total.checked_add(copies, DAY, "summing card copies")?;
+        count = i + 1;
+    }
+
+    let total = match (overrun, pending.front()) {
+        (Overrun::Error, Some(won)) => {
+            let (id, wins) = won.first_winner;
+            return Err(ScratchcardError::PastLastCard {
+                id,
+                wins,
+                remaining: count - id as usize,
+            });
+        }
+        (Overrun::Extend, _) => pending.into_iter().try_fold(total, |total, won| {
+            total.checked_add(won.copies, DAY, "summing card copies")
+        })?,
+        _ => total,
+    };
+
+    Ok(Scored { total, warnings })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_streaming_matches() {
+        let input = include_str!("sample.input");
+
+        assert_eq!(
+            part_1_streaming(input.as_bytes()).unwrap(),
+            part_1(input).unwrap()
+        );
+        assert_eq!(
+            part_2_streaming(input.as_bytes()).unwrap(),
+            part_2(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input| match part_1(input) {
+            Err(ScratchcardError::Parse(e)) => (e.line, e.column, e.kind),
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert_eq!(
+            error("Crad 1: 1 | 2"),
+            (1, 1, ParseErrorKind::MissingPrefix)
+        );
+        assert_eq!(error("Card 1 1 | 2"), (1, 13, ParseErrorKind::MissingColon));
+        assert_eq!(
+            error("Card 1: 1 | 2\nCard x: 1 | 2"),
+            (2, 6, ParseErrorKind::BadId("x".to_string()))
+        );
+        assert_eq!(error("Card 1: 1 2 3"), (1, 14, ParseErrorKind::MissingBar));
+        assert_eq!(
+            error("Card 1: 1 2 | 3 4a"),
+            (1, 17, ParseErrorKind::BadNumber("4a".to_string()))
+        );
+        assert_eq!(
+            error("Card 1: 1 | 2 | 3"),
+            (1, 15, ParseErrorKind::BadNumber("|".to_string()))
+        );
+        assert_eq!(
+            error("Card 1: 1 | 2\nCard 3: 1 | 2"),
+            (
+                2,
+                6,
+                ParseErrorKind::OutOfSequence {
+                    expected: 2,
+                    found: 3
+                }
+            )
+        );
+
+        let e = part_2_streaming("Card 1: 1 | 2\n\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Line 2, column 1: expected the line to start with `Card`"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_warnings() {
+        let cards = Cards::parse("Card 1: 5 7 5 | 5 9 9\nCard 2: 1 | 2").unwrap();
+
+        assert_eq!(
+            cards.warnings.kept,
+            vec![
+                Warning {
+                    line: 1,
+                    column: 13,
+                    kind: WarningKind::DuplicateWinning(5)
+                },
+                Warning {
+                    line: 1,
+                    column: 21,
+                    kind: WarningKind::DuplicateOurs(9)
+                },
+            ]
+        );
+        // The repeated 5 still only matches once
+        assert_eq!(cards.cards[0].matches, 1);
+
+        // Every way of solving keeps them
+        let input = "Card 1: 5 7 5 | 5 9 9\nCard 2: 1 | 2";
+        for scored in [
+            part_1_scored(input).unwrap(),
+            part_1_streaming_scored(input.as_bytes()).unwrap(),
+            part_2_with(input, Overrun::default()).unwrap(),
+            part_2_streaming_with(input.as_bytes(), Overrun::default()).unwrap(),
+        ] {
+            assert_eq!(scored.warnings, cards.warnings);
+        }
+
+        // Past the first few, warnings are only counted
+        let noisy = (1..=300)
+            .map(|id| format!("Card {}: 1 1 | 2", id))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let scored = part_1_streaming_scored(noisy.as_bytes()).unwrap();
+        assert_eq!(scored.warnings.kept.len(), Warnings::KEPT);
+        assert_eq!(scored.warnings.dropped, 300 - Warnings::KEPT);
+        assert_eq!(scored.warnings, Cards::parse(&noisy).unwrap().warnings);
+    }
+
+    /// Cards matching `wins[i]` of their numbers each, with ten numbers a side unless they
+    /// need more
+    fn generate(wins: &[usize]) -> String {
+        wins.iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let size = (*w).max(10);
+                let winning = (1..=size).map(|n| n.to_string()).collect::<Vec<_>>();
+                let ours = (1..=size)
+                    .map(|n| if n <= *w { n } else { n + 1000 }.to_string())
+                    .collect::<Vec<_>>();
+
+                format!("Card {}: {} | {}", i + 1, winning.join(" "), ours.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn both(input: &str, overrun: Overrun) -> Result<Answer, String> {
+        let batch = part_2_with(input, overrun).map_err(|e| e.to_string());
+        let streamed = part_2_streaming_with(input.as_bytes(), overrun).map_err(|e| e.to_string());
+        assert_eq!(batch, streamed);
+
+        batch.map(|scored| scored.total)
+    }
+
+    #[test]
+    fn test_overrun() {
+        // Card 2 wins three cards with two left, and card 4 two with none left
+        let input = generate(&[0, 3, 1, 2]);
+
+        assert_eq!(both(&input, Overrun::Clamp), Ok(Answer(1 + 1 + 2 + 4)));
+        // The blank cards get 1 copy from card 2, then 4 each from the copies of card 4
+        assert_eq!(
+            both(&input, Overrun::Extend),
+            Ok(Answer(1 + 1 + 2 + 4 + 5 + 4))
+        );
+        assert_eq!(
+            both(&input, Overrun::Error),
+            Err("Card 2 wins copies of the next 3 cards, but only 2 come after it".to_string())
+        );
+
+        // With nothing running past the end, the policies all agree
+        let input = generate(&[2, 1, 0, 0]);
+        for overrun in [Overrun::Clamp, Overrun::Error, Overrun::Extend] {
+            assert_eq!(both(&input, overrun), Ok(Answer(1 + 2 + 4 + 1)));
+        }
+    }
+
+    #[test]
+    fn test_overrun_generated() {
+        let mut seed = 7_u64;
+        let wins = (0..300)
+            .map(|_| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (seed >> 60) as usize % 4
+            })
+            .collect::<Vec<_>>();
+        let input = generate(&wins);
+
+        let clamped = both(&input, Overrun::Clamp).unwrap();
+        let extended = both(&input, Overrun::Extend).unwrap();
+        let error = both(&input, Overrun::Error);
+
+        // Extending only ever adds blank cards on the end
+        assert!(extended >= clamped);
+        assert_eq!(error.is_err(), extended != clamped);
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        // 127 is the last number that fits in the bitset, and 128 the first that doesn't
+        let cards =
+            Cards::parse("Card 1: 5 127 128 1000 4294967295 | 4294967295 1000 128 127 6 999")
+                .unwrap();
+        assert_eq!(cards.cards[0].matches, 4);
+
+        let mut numbers = Numbers::default();
+        assert!(numbers.insert(300) && numbers.insert(3) && numbers.insert(200));
+        assert!(!numbers.insert(300) && !numbers.insert(3));
+        assert_eq!(numbers.matches(&Numbers::default()), 0);
+        assert_eq!(numbers.matches(&numbers), 3);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::cards(7, 1000);
+        let cards = Cards::parse(&input).unwrap();
+
+        assert_eq!(cards.cards.len(), 1000);
+        assert!(cards.warnings.is_empty());
+        assert!(cards.cards.iter().all(|c| c.matches <= 2));
+        assert_eq!(input, generate::cards(7, 1000));
+
+        assert_eq!(part_1_hashset(&input).unwrap(), part_1(&input).unwrap());
+        assert_eq!(part_2_hashset(&input).unwrap(), part_2(&input).unwrap());
+    }
+
+    #[test]
+    fn test_cascade() {
+        let cascade = Cascade::trace(include_str!("sample.input"), Overrun::Clamp).unwrap();
+
+        let copies = cascade.cards.iter().map(|c| c.copies).collect::<Vec<_>>();
+        assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(cascade.cards[3].won_from, vec![(1, 1), (2, 2), (3, 4)]);
+
+        // Card 1 wins 2 to 5, card 2 wins 3 and 4, and so on back from the end
+        let contributions = cascade
+            .cards
+            .iter()
+            .map(|c| c.contribution)
+            .collect::<Vec<_>>();
+        assert_eq!(contributions, vec![15, 7, 4, 2, 1, 1]);
+        assert_eq!(cascade.total(), Ok(Answer(30)));
+
+        assert!(cascade.dot().contains("card3 -> card4 [label=\"4\"];"));
+        let table = cascade.table(true);
+        assert_eq!(
+            table
+                .lines()
+                .nth(1)
+                .unwrap()
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            vec!["1", "1", "15", "-"]
+        );
+    }
+
+    #[test]
+    fn test_cascade_blank_cards() {
+        let input = generate(&[0, 3, 1, 2]);
+        let cascade = Cascade::trace(&input, Overrun::Extend).unwrap();
+
+        assert_eq!(cascade.cards.len(), 6);
+        assert!(cascade.cards[4].blank && cascade.cards[4].contribution == 0);
+        assert_eq!(cascade.cards[4].won_from, vec![(2, 1), (4, 4)]);
+        assert_eq!(
+            cascade.total().map_err(|e| e.to_string()),
+            both(&input, Overrun::Extend)
+        );
+        assert_eq!(
+            Answer(cascade.cards.iter().map(|c| c.contribution as i128).sum()),
+            cascade.total().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // 127 matches is worth 2^126, as much as an answer can hold
+        assert_eq!(part_1(&generate(&[127])).unwrap(), Answer(1 << 126));
+        assert_eq!(
+            part_1(&generate(&[128])).unwrap_err().to_string(),
+            "Day 4 overflowed while scoring card 1, which matches 128"
+        );
+        assert_eq!(
+            part_1_streaming(generate(&[0, 128]).as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "Day 4 overflowed while scoring card 2, which matches 128"
+        );
+
+        // Every card winning all of the ones after it doubles the copies each time, so card 65
+        // would have 2^64
+        let input = generate(&(0..70).map(|i| 69 - i).collect::<Vec<_>>());
+        assert_eq!(
+            part_2(&input).unwrap_err().to_string(),
+            "Day 4 overflowed while adding card 64's copies to card 65"
+        );
+        // Streaming only adds a card's original once it's read, so 2^64 - 1 copies won fit
+        // until then
+        assert_eq!(
+            part_2_streaming(input.as_bytes()).unwrap_err().to_string(),
+            "Day 4 overflowed while counting copies of card 65"
+        );
+
+        let input = generate(&(0..64).map(|i| 63 - i).collect::<Vec<_>>());
+        assert_eq!(part_2(&input).unwrap(), Answer((1 << 64) - 1));
+        assert_eq!(both(&input, Overrun::Error), Ok(Answer((1 << 64) - 1)));
+    }
+}
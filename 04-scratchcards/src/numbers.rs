@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+/// A set of one side of a card's numbers
+pub(crate) trait NumberSet: Default {
+    /// Adds `n`, returning whether it wasn't already there
+    fn insert(&mut self, n: u32) -> bool;
+
+    /// How many numbers are in both sets
+    fn matches(&self, other: &Self) -> usize;
+}
+
+/// A set of card numbers. The puzzle's are all under 100, so those under 128 are bits of one
+/// `u128` and matching two sets is an AND and a popcount. Anything bigger goes in a sorted list
+/// on the side.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Numbers {
+    small: u128,
+    large: Vec<u32>,
+}
+
+impl NumberSet for Numbers {
+    fn insert(&mut self, n: u32) -> bool {
+        if n < u128::BITS {
+            let bit = 1 << n;
+            let added = self.small & bit == 0;
+            self.small |= bit;
+
+            added
+        } else {
+            match self.large.binary_search(&n) {
+                Ok(_) => false,
+                Err(i) => {
+                    self.large.insert(i, n);
+                    true
+                }
+            }
+        }
+    }
+
+    fn matches(&self, other: &Numbers) -> usize {
+        let small = (self.small & other.small).count_ones() as usize;
+        if self.large.is_empty() || other.large.is_empty() {
+            return small;
+        }
+
+        // Both lists are sorted, so one pass through them finds every number they share
+        let (mut a, mut b) = (self.large.iter().peekable(), other.large.iter().peekable());
+        let mut large = 0;
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            match x.cmp(y) {
+                std::cmp::Ordering::Less => {
+                    a.next();
+                }
+                std::cmp::Ordering::Greater => {
+                    b.next();
+                }
+                std::cmp::Ordering::Equal => {
+                    large += 1;
+                    a.next();
+                    b.next();
+                }
+            }
+        }
+
+        small + large
+    }
+}
+
+/// What every card was read into before [`Numbers`], kept to time it against
+impl NumberSet for HashSet<u32> {
+    fn insert(&mut self, n: u32) -> bool {
+        HashSet::insert(self, n)
+    }
+
+    fn matches(&self, other: &Self) -> usize {
+        self.intersection(other).count()
+    }
+}
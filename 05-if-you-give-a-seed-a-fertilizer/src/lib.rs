@@ -0,0 +1,235 @@
+use answer::{Answer, OrOverflow, Overflow};
+
+const DAY: u8 = 5;
+
+trait Seeds: Sized {
+    fn parse(input: &str) -> Result<Self, Overflow>;
+    fn seeds(&self) -> Vec<u64>;
+}
+
+struct Part1Seeds {
+    seeds: Vec<u64>,
+}
+
+impl Seeds for Part1Seeds {
+    fn parse(input: &str) -> Result<Self, Overflow> {
+        let seeds = input
+            .strip_prefix("seeds: ")
+            .unwrap()
+            .split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect::<Vec<_>>();
+
+        Ok(Self { seeds })
+    }
+
+    fn seeds(&self) -> Vec<u64> {
+        self.seeds.clone()
+    }
+}
+
+#[derive(Debug)]
+struct SeedEntry {
+    seed_start: u64,
+    count: u64,
+}
+
+impl SeedEntry {
+    fn seeds(&self) -> Vec<u64> {
+        (self.seed_start..self.seed_start + self.count).collect::<Vec<_>>()
+    }
+
+    fn contains(&self, p: u64) -> bool {
+        p >= self.seed_start && p < self.seed_start + self.count
+    }
+}
+
+#[derive(Debug)]
+struct Part2Seeds {
+    entries: Vec<SeedEntry>,
+}
+
+impl Seeds for Part2Seeds {
+    fn parse(input: &str) -> Result<Self, Overflow> {
+        let seeds = input
+            .strip_prefix("seeds: ")
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>();
+
+        let entries = (0..seeds.len())
+            .step_by(2)
+            .map(|i| {
+                let seed_start: u64 = seeds[i].parse().unwrap();
+                let count = seeds[i + 1].parse().unwrap();
+
+                // Checked once here so the range arithmetic later on can't overflow
+                seed_start
+                    .checked_add(count)
+                    .or_overflow_with(DAY, || format!("reading seed range {}", i / 2))?;
+
+                Ok(SeedEntry { seed_start, count })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    fn seeds(&self) -> Vec<u64> {
+        self.entries.iter().flat_map(|e| e.seeds()).collect()
+    }
+}
+
+#[derive(Debug)]
+struct Input<SeedType: Seeds> {
+    seeds: SeedType,
+    maps: Vec<Map>,
+}
+
+impl<SeedType: Seeds> Input<SeedType> {
+    fn parse(input: &str) -> Result<Self, Overflow> {
+        let mut sections = input.split("\n\n");
+
+        let seeds = sections.next().unwrap();
+        let seeds = SeedType::parse(seeds)?;
+
+        let maps = sections.map(Map::parse).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { seeds, maps })
+    }
+
+    fn mapped_value(&self, mut seed: u64) -> u64 {
+        for map in &self.maps {
+            let entry: Option<u64> = map.entries.iter().find_map(|e| e.translate_down(seed));
+            seed = entry.unwrap_or(seed);
+        }
+
+        seed
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    entries: Vec<MapEntry>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, Overflow> {
+        let mut lines = input.lines();
+        let _ = lines.next().unwrap();
+
+        let entries = lines.map(MapEntry::parse).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { entries })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MapEntry {
+    dest_range_start: u64,
+    source_range_start: u64,
+    range_length: u64,
+}
+
+fn important_points(maps: &[Map]) -> Vec<u64> {
+    let mut maps = maps.to_vec();
+    maps.reverse();
+    let maps = maps;
+
+    let mut points = vec![];
+
+    for m in maps {
+        let mut translated_points = points
+            .iter()
+            .map(|p| {
+                m.entries
+                    .iter()
+                    .find_map(|e| e.translate_up(*p))
+                    .unwrap_or(*p)
+            })
+            .collect::<Vec<_>>();
+        let mut new_points = m
+            .entries
+            .iter()
+            .map(|e| e.source_range_start)
+            .collect::<Vec<_>>();
+        translated_points.append(&mut new_points);
+
+        points = translated_points;
+    }
+
+    points
+}
+
+impl MapEntry {
+    fn parse(l: &str) -> Result<Self, Overflow> {
+        let nums = l
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect::<Vec<u64>>();
+
+        // Both ends of both ranges have to fit, otherwise translating could overflow
+        let step = || format!("reading map entry {}", l);
+        nums[0].checked_add(nums[2]).or_overflow_with(DAY, step)?;
+        nums[1].checked_add(nums[2]).or_overflow_with(DAY, step)?;
+
+        Ok(Self {
+            dest_range_start: nums[0],
+            source_range_start: nums[1],
+            range_length: nums[2],
+        })
+    }
+
+    fn translate_down(&self, seed: u64) -> Option<u64> {
+        if seed >= self.source_range_start && seed < self.source_range_start + self.range_length {
+            let offset = seed - self.source_range_start;
+            Some(self.dest_range_start + offset)
+        } else {
+            None
+        }
+    }
+
+    fn translate_up(&self, seed: u64) -> Option<u64> {
+        if seed >= self.dest_range_start && seed < self.dest_range_start + self.range_length {
+            let offset = seed - self.dest_range_start;
+            Some(self.source_range_start + offset)
+        } else {
+            None
+        }
+    }
+}
+
+fn solve<SeedType: Seeds>(sample_input: &str) -> Result<Answer, Overflow> {
+    let input = Input::<SeedType>::parse(sample_input)?;
+
+    let lowest = input
+        .seeds
+        .seeds()
+        .iter()
+        .map(|seed| input.mapped_value(*seed))
+        .min()
+        .unwrap();
+
+    Ok(Answer(lowest.into()))
+}
+
+pub fn part_1(input: &str) -> Result<Answer, Overflow> {
+    solve::<Part1Seeds>(input)
+}
+
+pub fn part_2(input: &str) -> Result<Answer, Overflow> {
+    let input = Input::<Part2Seeds>::parse(input)?;
+    let important_points = important_points(&input.maps);
+    let p = important_points
+        .iter()
+        .filter(|p| input.seeds.entries.iter().any(|s| s.contains(**p)))
+        .collect::<Vec<_>>();
+
+    let lowest = p
+        .iter()
+        .map(|seed| input.mapped_value(**seed))
+        .min()
+        .unwrap();
+
+    Ok(Answer(lowest.into()))
+}
@@ -0,0 +1,109 @@
+use std::ops::RangeInclusive;
+
+use answer::{Answer, CheckedAnswer, OrOverflow, Overflow};
+
+const DAY: u8 = 6;
+
+struct Part1Input {
+    races: Vec<Race>,
+}
+
+impl Part1Input {
+    fn parse(input: &str) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+        let times = lines[0];
+        let distances = lines[1];
+
+        let times = times.strip_prefix("Time:").unwrap().trim();
+        let distances = distances.strip_prefix("Distance:").unwrap().trim();
+
+        let times = times.split_whitespace().map(|s| s.parse::<u64>().unwrap());
+        let distances = distances
+            .split_whitespace()
+            .map(|s| s.parse::<u64>().unwrap());
+
+        let races = times
+            .zip(distances)
+            .map(|(time, record_distance)| Race {
+                time,
+                record_distance,
+            })
+            .collect::<Vec<_>>();
+
+        Part1Input { races }
+    }
+}
+
+struct Part2Input {
+    race: Race,
+}
+
+impl Part2Input {
+    fn parse(input: &str) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+        let times = lines[0];
+        let distances = lines[1];
+
+        let time = times.strip_prefix("Time:").unwrap().trim();
+        let distance = distances.strip_prefix("Distance:").unwrap().trim();
+
+        let time = time.replace(' ', "").parse::<u64>().unwrap();
+        let record_distance = distance.replace(' ', "").parse::<u64>().unwrap();
+
+        let race = Race {
+            time,
+            record_distance,
+        };
+
+        Self { race }
+    }
+}
+
+struct Race {
+    time: u64,
+    record_distance: u64,
+}
+
+impl Race {
+    fn possible_win_range(&self) -> Result<RangeInclusive<u64>, Overflow> {
+        let mut min = None;
+        let mut max = None;
+        for i in 1..=self.time {
+            let velocity = i;
+            let time_left = self.time - i;
+
+            let distance = velocity.checked_mul(time_left).or_overflow_with(DAY, || {
+                format!("holding the button for {}ms of a {}ms race", i, self.time)
+            })?;
+
+            if distance > self.record_distance {
+                if min.is_none() {
+                    min = Some(i);
+                }
+                max = Some(i);
+            }
+        }
+
+        Ok(min.unwrap()..=max.unwrap())
+    }
+}
+
+pub fn part_1(input: &str) -> Result<Answer, Overflow> {
+    let input = Part1Input::parse(input);
+    let counts = input
+        .races
+        .iter()
+        .map(|r| r.possible_win_range().map(|range| range.count()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    counts
+        .into_iter()
+        .checked_product(DAY, "multiplying the ways to win")
+}
+
+pub fn part_2(input: &str) -> Result<Answer, Overflow> {
+    let input = Part2Input::parse(input);
+    let count = input.race.possible_win_range()?.count();
+
+    Ok(Answer(count as i128))
+}
@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+
+use answer::{Answer, CheckedAnswer, OrOverflow, Overflow};
+
+const DAY: u8 = 7;
+
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub(crate) struct Hand<CardType> {
+    cards: [CardType; 5],
+    pub(crate) bid: usize,
+}
+
+impl<CardType> Ord for Hand<CardType>
+where
+    CardType: Eq + std::hash::Hash + Copy + Ord + std::fmt::Debug,
+    Hand<CardType>: Scorable,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.hand_type()
+            .cmp(&other.hand_type())
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl<CardType> PartialOrd for Hand<CardType>
+where
+    Hand<CardType>: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CardType: From<char> + std::fmt::Debug + Eq + std::hash::Hash + Copy> Hand<CardType> {
+    fn parse(input: &str) -> Self {
+        let split = input.split_whitespace().collect::<Vec<_>>();
+        let cards = split[0];
+        let bid = split[1];
+
+        let cards = cards.chars().map(CardType::from).collect::<Vec<_>>();
+        let cards = cards.try_into().unwrap();
+
+        let bid = bid.parse().unwrap();
+
+        Self { cards, bid }
+    }
+}
+
+impl Scorable for Hand<Card> {
+    fn hand_type(&self) -> HandType {
+        let mut card_count = HashMap::<Card, usize>::new();
+
+        for c in self.cards {
+            card_count.entry(c).and_modify(|c| *c += 1).or_insert(1);
+        }
+
+        if card_count.len() == 1 {
+            return HandType::FiveOfAKind;
+        }
+
+        if card_count.values().any(|c| *c == 4) {
+            return HandType::FourOfAKind;
+        }
+
+        if card_count.values().any(|c| *c == 3) && card_count.values().any(|c| *c == 2) {
+            return HandType::FullHouse;
+        }
+
+        if card_count.values().any(|c| *c == 3) {
+            return HandType::ThreeOfAKind;
+        }
+
+        if card_count.values().filter(|c| **c == 2).count() == 2 {
+            return HandType::TwoPair;
+        }
+
+        if card_count.values().any(|c| *c == 2) {
+            return HandType::OnePair;
+        }
+
+        HandType::HighCard
+    }
+}
+
+trait Scorable {
+    fn hand_type(&self) -> HandType;
+}
+
+impl Scorable for Hand<JokerCard> {
+    fn hand_type(&self) -> HandType {
+        let mut card_count = HashMap::<JokerCard, usize>::new();
+
+        for c in self.cards {
+            card_count.entry(c).and_modify(|c| *c += 1).or_insert(1);
+        }
+
+        let joker_count = card_count.remove(&JokerCard(Card::Jack)).unwrap_or(0);
+
+        if card_count.len() <= 1 {
+            return HandType::FiveOfAKind;
+        }
+
+        if card_count.values().any(|c| *c + joker_count == 4) {
+            return HandType::FourOfAKind;
+        }
+
+        // Zero Joker Count Case
+        if card_count.values().any(|c| *c == 3) && card_count.values().any(|c| *c == 2) {
+            return HandType::FullHouse;
+        }
+        // One Joker Count Case
+        if joker_count == 1 && card_count.values().filter(|c| **c == 2).count() == 2 {
+            return HandType::FullHouse;
+        }
+        // Two Joker Count Case
+        if joker_count == 2
+            && card_count.values().any(|c| *c == 2)
+            && card_count.values().any(|c| *c == 1)
+        {
+            return HandType::FullHouse;
+        }
+
+        if card_count.values().any(|c| *c + joker_count == 3) {
+            return HandType::ThreeOfAKind;
+        }
+
+        // No Joker Case
+        if card_count.values().filter(|c| **c == 2).count() == 2 {
+            return HandType::TwoPair;
+        }
+        //One Joker Case
+        if joker_count == 1 && card_count.values().filter(|c| **c == 2).count() == 1 {
+            return HandType::TwoPair;
+        }
+
+        if card_count.values().any(|c| *c + joker_count == 2) {
+            return HandType::OnePair;
+        }
+
+        HandType::HighCard
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub(crate) struct JokerCard(Card);
+
+impl Ord for JokerCard {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.0 == Card::Jack && other.0 == Card::Jack {
+            return std::cmp::Ordering::Equal;
+        }
+        if self.0 == Card::Jack {
+            return std::cmp::Ordering::Less;
+        }
+        if other.0 == Card::Jack {
+            return std::cmp::Ordering::Greater;
+        }
+
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for JokerCard {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<char> for Card {
+    fn from(value: char) -> Self {
+        match value {
+            'A' => Card::Ace,
+            'K' => Card::King,
+            'Q' => Card::Queen,
+            'J' => Card::Jack,
+            'T' => Card::Ten,
+            '9' => Card::Nine,
+            '8' => Card::Eight,
+            '7' => Card::Seven,
+            '6' => Card::Six,
+            '5' => Card::Five,
+            '4' => Card::Four,
+            '3' => Card::Three,
+            '2' => Card::Two,
+            _ => panic!("Invalid card: {}", value),
+        }
+    }
+}
+
+impl From<char> for JokerCard {
+    fn from(value: char) -> Self {
+        JokerCard(Card::from(value))
+    }
+}
+
+impl From<Card> for char {
+    fn from(value: Card) -> Self {
+        match value {
+            Card::Ace => 'A',
+            Card::King => 'K',
+            Card::Queen => 'Q',
+            Card::Jack => 'J',
+            Card::Ten => 'T',
+            Card::Nine => '9',
+            Card::Eight => '8',
+            Card::Seven => '7',
+            Card::Six => '6',
+            Card::Five => '5',
+            Card::Four => '4',
+            Card::Three => '3',
+            Card::Two => '2',
+        }
+    }
+}
+
+impl From<JokerCard> for char {
+    fn from(value: JokerCard) -> Self {
+        char::from(value.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub(crate) enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    const ALL: [(HandType, &'static str); 7] = [
+        (HandType::HighCard, "high-card"),
+        (HandType::OnePair, "one-pair"),
+        (HandType::TwoPair, "two-pair"),
+        (HandType::ThreeOfAKind, "three-of-a-kind"),
+        (HandType::FullHouse, "full-house"),
+        (HandType::FourOfAKind, "four-of-a-kind"),
+        (HandType::FiveOfAKind, "five-of-a-kind"),
+    ];
+
+    fn parse(input: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|(_, name)| *name == input)
+            .map(|(t, _)| *t)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Input<CardType> {
+    pub(crate) hands: Vec<Hand<CardType>>,
+}
+
+impl<CardType: From<char> + std::fmt::Debug + Eq + std::hash::Hash + Copy> Input<CardType> {
+    pub(crate) fn parse(input: &str) -> Self {
+        let hands = input.lines().map(Hand::parse).collect();
+
+        Self { hands }
+    }
+}
+
+impl<CardType: Into<char> + Copy> Hand<CardType> {
+    fn label(&self) -> String {
+        self.cards.iter().map(|c| (*c).into()).collect()
+    }
+}
+
+/// Holds a parsed set of hands so they can be queried from the runner's REPL
+pub struct Explorer {
+    hands: Input<Card>,
+    joker_hands: Input<JokerCard>,
+}
+
+impl Explorer {
+    pub const QUERIES: &'static [&'static str] = &[
+        "types - count the hands of each type",
+        "hands <type> - list the hands of a type, e.g. `hands full-house`",
+        "joker-hands <type> - like `hands`, but treating J as a joker",
+    ];
+
+    pub fn parse(input: &str) -> Self {
+        Self {
+            hands: Input::parse(input),
+            joker_hands: Input::parse(input),
+        }
+    }
+
+    pub fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+        match (query, args) {
+            ("types", []) => Ok(HandType::ALL
+                .iter()
+                .map(|(hand_type, name)| {
+                    let count = self
+                        .hands
+                        .hands
+                        .iter()
+                        .filter(|h| h.hand_type() == *hand_type)
+                        .count();
+                    let joker_count = self
+                        .joker_hands
+                        .hands
+                        .iter()
+                        .filter(|h| h.hand_type() == *hand_type)
+                        .count();
+
+                    format!("{}: {} ({} with jokers)", name, count, joker_count)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")),
+            ("hands", [hand_type]) => Ok(hands_of_type(&self.hands, parse_hand_type(hand_type)?)),
+            ("joker-hands", [hand_type]) => Ok(hands_of_type(
+                &self.joker_hands,
+                parse_hand_type(hand_type)?,
+            )),
+            _ => Err(format!("Unknown query: {}", query)),
+        }
+    }
+}
+
+fn parse_hand_type(input: &str) -> Result<HandType, String> {
+    HandType::parse(input).ok_or_else(|| {
+        let names = HandType::ALL.map(|(_, name)| name);
+        format!(
+            "Unknown hand type {}, expected one of {}",
+            input,
+            names.join(", ")
+        )
+    })
+}
+
+fn hands_of_type<CardType>(input: &Input<CardType>, hand_type: HandType) -> String
+where
+    CardType: Into<char> + Copy,
+    Hand<CardType>: Scorable,
+{
+    input
+        .hands
+        .iter()
+        .filter(|h| h.hand_type() == hand_type)
+        .map(|h| format!("{} {}", h.label(), h.bid))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part_1(sample_input: &str) -> Result<Answer, Overflow> {
+    let mut input = Input::<Card>::parse(sample_input);
+    input.hands.sort();
+
+    let winnings = input
+        .hands
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            let multiplier = i + 1;
+
+            h.bid
+                .checked_mul(multiplier)
+                .or_overflow_with(DAY, || format!("scoring hand {}", h.label()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    winnings.into_iter().checked_sum(DAY, "summing winnings")
+}
+
+pub fn part_2(sample_input: &str) -> Result<Answer, Overflow> {
+    let mut input = Input::<JokerCard>::parse(sample_input);
+    input.hands.sort();
+
+    let winnings = input
+        .hands
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            let multiplier = i + 1;
+
+            h.bid
+                .checked_mul(multiplier)
+                .or_overflow_with(DAY, || format!("scoring hand {}", h.label()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    winnings.into_iter().checked_sum(DAY, "summing winnings")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_four_of_kinds() {
+        let a = Hand::<Card>::parse("33332 1");
+        let b = Hand::<Card>::parse("2AAAA 2");
+
+        assert!(a > b);
+        assert_eq!(a.hand_type(), HandType::FourOfAKind);
+        assert_eq!(b.hand_type(), HandType::FourOfAKind);
+    }
+
+    #[test]
+    fn test_full_house() {
+        let a = Hand::<Card>::parse("77888 1");
+        let b = Hand::<Card>::parse("77788 2");
+
+        assert!(a > b);
+        assert_eq!(a.hand_type(), HandType::FullHouse);
+        assert_eq!(b.hand_type(), HandType::FullHouse);
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use answer::{Answer, OrOverflow, Overflow};
+
+const DAY: u8 = 8;
+
+#[derive(Debug)]
+struct Map {
+    instructions: Vec<Direction>,
+    nodes: HashMap<String, Node>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Self {
+        let mut split = input.split("\n\n");
+        let instructions = split.next().unwrap();
+        let nodes = split.next().unwrap();
+
+        let instructions = instructions
+            .chars()
+            .map(|c| match c {
+                'L' => Direction::Left,
+                'R' => Direction::Right,
+                _ => panic!("Invalid direction"),
+            })
+            .collect::<Vec<_>>();
+
+        let nodes = nodes
+            .lines()
+            .map(Node::parse)
+            .map(|n| (n.name.clone(), n))
+            .collect::<HashMap<String, Node>>();
+
+        Self {
+            instructions,
+            nodes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn parse(input: &str) -> Self {
+        let mut name_and_connections = input.split(" = ");
+        let name = name_and_connections.next().unwrap();
+
+        let connections = name_and_connections.next().unwrap();
+        let connections = connections
+            .strip_prefix("(")
+            .unwrap()
+            .strip_suffix(")")
+            .unwrap();
+        let mut connections = connections.split(", ");
+
+        let left = connections.next().unwrap();
+        let right = connections.next().unwrap();
+
+        Self {
+            name: name.to_string(),
+            left: left.to_string(),
+            right: right.to_string(),
+        }
+    }
+}
+
+/// Holds a parsed map so its nodes can be queried from the runner's REPL
+pub struct Explorer {
+    map: Map,
+}
+
+impl Explorer {
+    pub const QUERIES: &'static [&'static str] = &[
+        "neighbours <node> - show where a node leads, and which nodes lead to it",
+        "starts - list the part 2 starting nodes",
+    ];
+
+    pub fn parse(input: &str) -> Self {
+        Self {
+            map: Map::parse(input),
+        }
+    }
+
+    pub fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+        match (query, args) {
+            ("neighbours", [name]) => {
+                let node = self
+                    .map
+                    .nodes
+                    .get(*name)
+                    .ok_or_else(|| format!("No node named {}", name))?;
+
+                let mut incoming = self
+                    .map
+                    .nodes
+                    .values()
+                    .filter(|n| n.left == node.name || n.right == node.name)
+                    .map(|n| n.name.clone())
+                    .collect::<Vec<_>>();
+                incoming.sort();
+
+                Ok(format!(
+                    "left: {}\nright: {}\nreached from: {}",
+                    node.left,
+                    node.right,
+                    incoming.join(", ")
+                ))
+            }
+            ("starts", []) => {
+                let mut starts = self
+                    .map
+                    .nodes
+                    .keys()
+                    .filter(|n| n.ends_with('A'))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                starts.sort();
+
+                Ok(starts.join(", "))
+            }
+            _ => Err(format!("Unknown query: {}", query)),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<Answer, Overflow> {
+    let map = Map::parse(input);
+
+    let mut count = 0_usize;
+    let mut current_node_name = "AAA".to_string();
+    let mut intruction_iter = map.instructions.iter().cycle();
+
+    while current_node_name != "ZZZ" {
+        let current_instruction = intruction_iter.next().unwrap();
+        let current_node = map.nodes.get(&current_node_name).unwrap();
+
+        let next_node_name = match current_instruction {
+            Direction::Left => current_node.left.clone(),
+            Direction::Right => current_node.right.clone(),
+        };
+
+        current_node_name = next_node_name;
+        count = count
+            .checked_add(1)
+            .or_overflow(DAY, "counting the steps to ZZZ")?;
+    }
+
+    Answer(0).checked_add(count, DAY, "counting the steps to ZZZ")
+}
+
+pub fn part_2(input: &str) -> Result<Answer, Overflow> {
+    let map = Map::parse(input);
+
+    let starting_positions = map
+        .nodes
+        .iter()
+        .filter(|(_, n)| n.name.ends_with('A'))
+        .map(|(_, n)| n.name.clone())
+        .collect::<Vec<_>>();
+
+    let mut count = 0_usize;
+    let mut current_node_names = starting_positions;
+    let mut intruction_iter = map.instructions.iter().cycle();
+
+    while !current_node_names.iter().all(|n| n.ends_with('Z')) {
+        let current_instruction = intruction_iter.next().unwrap();
+
+        for current_node_name in current_node_names.iter_mut() {
+            let current_node = map.nodes.get(current_node_name).unwrap();
+
+            let next_node_name = match current_instruction {
+                Direction::Left => current_node.left.clone(),
+                Direction::Right => current_node.right.clone(),
+            };
+
+            *current_node_name = next_node_name;
+        }
+
+        count = count
+            .checked_add(1)
+            .or_overflow(DAY, "counting the steps until every ghost is on a Z")?;
+    }
+
+    Answer(0).checked_add(count, DAY, "counting the steps until every ghost is on a Z")
+}
+
+pub fn part2_try2(input: &str) -> Result<Answer, Overflow> {
+    let map = Map::parse(input);
+
+    let starting_positions = map
+        .nodes
+        .iter()
+        .filter(|(_, n)| n.name.ends_with('A'))
+        .map(|(_, n)| n.name.clone())
+        .collect::<Vec<_>>();
+
+    let mut to_end_counts = HashMap::<String, usize>::new();
+
+    for start in starting_positions.iter() {
+        let mut current_node_name = start.clone();
+        let mut count = 0_usize;
+        let mut intruction_iter = map.instructions.iter().cycle();
+
+        while !current_node_name.ends_with('Z') {
+            let current_instruction = intruction_iter.next().unwrap();
+            let current_node = map.nodes.get(&current_node_name).unwrap();
+
+            let next_node_name = match current_instruction {
+                Direction::Left => current_node.left.clone(),
+                Direction::Right => current_node.right.clone(),
+            };
+
+            current_node_name = next_node_name;
+            count = count
+                .checked_add(1)
+                .or_overflow_with(DAY, || format!("counting the steps from {}", start))?;
+        }
+
+        to_end_counts.insert(start.clone(), count);
+    }
+
+    let counts = to_end_counts.values().cloned().collect::<Vec<_>>();
+
+    let lcm = least_common_multiple(&counts)?;
+
+    i128::try_from(lcm)
+        .ok()
+        .or_overflow(DAY, "converting the least common multiple to an answer")
+        .map(Answer)
+}
+
+// The LCM of a handful of cycle lengths quickly outgrows a usize, so this works in u128 and
+// divides before multiplying to keep the intermediate value no bigger than the result.
+fn least_common_multiple(nums: &[usize]) -> Result<u128, Overflow> {
+    let mut result: u128 = 1;
+    for &num in nums {
+        let num = num as u128;
+        result = (num / gcd(num, result))
+            .checked_mul(result)
+            .or_overflow(DAY, "taking the least common multiple of the path lengths")?;
+    }
+    Ok(result)
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        return a;
+    }
+
+    gcd(b, a % b)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lcm_wider_than_usize() {
+        let a = usize::MAX as u128;
+        let b = (usize::MAX - 1) as u128;
+
+        assert_eq!(
+            least_common_multiple(&[usize::MAX, usize::MAX - 1]),
+            Ok(a * b)
+        );
+    }
+
+    #[test]
+    fn test_lcm_overflow() {
+        let ans = least_common_multiple(&[usize::MAX, usize::MAX - 1, usize::MAX - 2]);
+
+        assert_eq!(
+            ans,
+            Err(Overflow::new(
+                DAY,
+                "taking the least common multiple of the path lengths"
+            ))
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::io::BufRead;
+
+use answer::{Answer, CheckedAnswer, OrOverflow, Overflow};
+
+const DAY: u8 = 9;
+
+struct History(Vec<i64>);
+
+#[derive(Debug)]
+pub enum HistoryError {
+    /// A value that isn't a whole number, or is too big for an `i64`. The line counts from 1.
+    BadValue {
+        line: usize,
+        value: String,
+    },
+    Overflow(Overflow),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::BadValue { line, value } => {
+                write!(
+                    f,
+                    "Line {}: {:?} isn't a whole number that fits in an i64",
+                    line, value
+                )
+            }
+            HistoryError::Overflow(e) => e.fmt(f),
+            HistoryError::Io(e) => write!(f, "Failed to read input: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+impl From<Overflow> for HistoryError {
+    fn from(e: Overflow) -> Self {
+        HistoryError::Overflow(e)
+    }
+}
+
+impl From<std::io::Error> for HistoryError {
+    fn from(e: std::io::Error) -> Self {
+        HistoryError::Io(e)
+    }
+}
+
+fn parse_histories(input: &str) -> Result<Vec<History>, HistoryError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| History::parse(line, i + 1))
+        .collect()
+}
+
+pub fn part_1(input: &str) -> Result<Answer, HistoryError> {
+    let histories = parse_histories(input)?;
+
+    let next_items = histories
+        .into_iter()
+        .map(|h| h.next_item())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(next_items
+        .into_iter()
+        .checked_sum(DAY, "summing extrapolated values")?)
+}
+
+pub fn part_2(input: &str) -> Result<Answer, HistoryError> {
+    let histories = parse_histories(input)?;
+
+    let previous_items = histories
+        .into_iter()
+        .map(|h| h.previous_item())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(previous_items
+        .into_iter()
+        .checked_sum(DAY, "summing extrapolated values")?)
+}
+
+/// Like [`part_1`], but reads one history at a time so the input never has to fit in memory
+pub fn part_1_streaming(input: impl BufRead) -> Result<Answer, HistoryError> {
+    let mut total = Answer(0);
+    for (i, line) in input.lines().enumerate() {
+        let next_item = History::parse(&line?, i + 1)?.next_item()?;
+
+        total = total.checked_add(next_item, DAY, "summing extrapolated values")?;
+    }
+
+    Ok(total)
+}
+
+/// Like [`part_2`], but reads one history at a time so the input never has to fit in memory
+pub fn part_2_streaming(input: impl BufRead) -> Result<Answer, HistoryError> {
+    let mut total = Answer(0);
+    for (i, line) in input.lines().enumerate() {
+        let previous_item = History::parse(&line?, i + 1)?.previous_item()?;
+
+        total = total.checked_add(previous_item, DAY, "summing extrapolated values")?;
+    }
+
+    Ok(total)
+}
+
+impl History {
+    /// Reads the history on line `line`, counting from 1
+    fn parse(input: &str, line: usize) -> Result<Self, HistoryError> {
+        let values = input
+            .split_ascii_whitespace()
+            .map(|n| {
+                n.parse::<i64>().map_err(|_| HistoryError::BadValue {
+                    line,
+                    value: n.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(values))
+    }
+
+    fn next_item(&self) -> Result<i128, Overflow> {
+        let widened = self.0.iter().map(|&n| n.into()).collect::<Vec<_>>();
+
+        next_number(&widened)
+    }
+
+    fn previous_item(&self) -> Result<i128, Overflow> {
+        let mut reversed = self.0.iter().map(|&n| n.into()).collect::<Vec<_>>();
+        reversed.reverse();
+
+        next_number(&reversed)
+    }
+}
+
+// Differences grow quickly on high degree sequences, so they're taken in i128 rather than the
+// i64 the history was read as
+fn next_number(arr: &[i128]) -> Result<i128, Overflow> {
+    let differences = arr
+        .windows(2)
+        .map(|w| {
+            w[1].checked_sub(w[0])
+                .or_overflow(DAY, "taking differences")
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let last = arr.last().unwrap();
+
+    if differences.iter().all(|&d| d == 0) {
+        return Ok(*last);
+    }
+
+    let next_difference = next_number(&differences)?;
+    last.checked_add(next_difference)
+        .or_overflow(DAY, "extrapolating the next value")
+}
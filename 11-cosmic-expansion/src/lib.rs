@@ -0,0 +1,254 @@
+use answer::{Answer, CheckedAnswer, OrOverflow, Overflow};
+
+const DAY: u8 = 11;
+
+#[derive(Debug, Clone)]
+struct Universe(Vec<Vec<Cell>>);
+
+impl Universe {
+    fn parse(input: &str) -> Self {
+        let universe = input
+            .lines()
+            .map(|l| l.chars().map(Cell::parse).collect())
+            .collect();
+
+        Self(universe)
+    }
+
+    fn is_row_empty(&self, row: usize) -> bool {
+        self.0[row].iter().all(|c| *c == Cell::Empty)
+    }
+
+    fn is_col_empty(&self, col: usize) -> bool {
+        self.0.iter().all(|r| r[col] == Cell::Empty)
+    }
+
+    fn get_empty_rows(&self) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.is_row_empty(*i))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn get_empty_cols(&self) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.is_col_empty(*i))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn expand_row(&self, row: usize) -> Self {
+        let mut new_universe = self.clone();
+
+        new_universe
+            .0
+            .insert(row, vec![Cell::Empty; self.0[row].len()]);
+
+        new_universe
+    }
+
+    fn expand_col(&self, col: usize) -> Self {
+        let mut new_universe = self.clone();
+
+        for row in new_universe.0.iter_mut() {
+            row.insert(col, Cell::Empty);
+        }
+
+        new_universe
+    }
+
+    fn expand(&self) -> Self {
+        let mut new_universe = self.clone();
+
+        let empty_rows = self.get_empty_rows();
+        let empty_cols = self.get_empty_cols();
+
+        for (i, row) in empty_rows.into_iter().enumerate() {
+            new_universe = new_universe.expand_row(row + i);
+        }
+
+        for (i, col) in empty_cols.into_iter().enumerate() {
+            new_universe = new_universe.expand_col(col + i);
+        }
+
+        new_universe
+    }
+
+    fn galaxies(&self) -> Vec<(usize, usize)> {
+        let mut galaxies = vec![];
+
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                if *cell == Cell::Galaxy {
+                    galaxies.push((i, j));
+                }
+            }
+        }
+
+        galaxies
+    }
+
+    fn galaxy_pairs(&self) -> Vec<((usize, usize), (usize, usize))> {
+        let mut pairs = vec![];
+
+        let galaxies = self.galaxies();
+
+        for (i, galaxy) in galaxies.iter().enumerate() {
+            for galaxy_match in galaxies.iter().skip(i + 1) {
+                pairs.push((*galaxy, *galaxy_match));
+            }
+        }
+
+        pairs
+    }
+
+    fn expanded_galaxies(&self, expansion_factor: usize) -> Result<Vec<Galaxy>, Overflow> {
+        let mut galaxies = self
+            .galaxies()
+            .into_iter()
+            .map(|g| Galaxy {
+                original: g,
+                expanded: g,
+            })
+            .collect::<Vec<_>>();
+
+        for row in self.get_empty_rows() {
+            for g in galaxies.iter_mut() {
+                if g.original.0 >= row {
+                    g.expanded.0 = g
+                        .expanded
+                        .0
+                        .checked_add(expansion_factor - 1)
+                        .or_overflow_with(DAY, || format!("expanding row {}", row))?;
+                }
+            }
+        }
+
+        for col in self.get_empty_cols() {
+            for g in galaxies.iter_mut() {
+                if g.original.1 >= col {
+                    g.expanded.1 = g
+                        .expanded
+                        .1
+                        .checked_add(expansion_factor - 1)
+                        .or_overflow_with(DAY, || format!("expanding column {}", col))?;
+                }
+            }
+        }
+
+        Ok(galaxies)
+    }
+
+    fn sum_of_distances(&self) -> Result<Answer, Overflow> {
+        self.galaxy_pairs()
+            .into_iter()
+            .map(|((x1, y1), (x2, y2))| x1.abs_diff(x2) as i128 + y1.abs_diff(y2) as i128)
+            .checked_sum(DAY, "summing distances")
+    }
+}
+
+struct Galaxy {
+    original: (usize, usize),
+    expanded: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Galaxy,
+}
+
+impl Cell {
+    fn parse(input: char) -> Self {
+        match input {
+            '.' => Self::Empty,
+            '#' => Self::Galaxy,
+            _ => panic!("Invalid cell"),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<Answer, Overflow> {
+    part_2(input, 2)
+}
+
+/// Part 1 done the literal way, by inserting the extra rows and columns into the grid before
+/// measuring. Only practical for small expansion factors, which is why [`part_2`] shifts the
+/// galaxy coordinates instead.
+pub fn part_1_expanded(input: &str) -> Result<Answer, Overflow> {
+    let universe = Universe::parse(input);
+
+    universe.expand().sum_of_distances()
+}
+
+pub fn part_2(input: &str, expansion_factor: usize) -> Result<Answer, Overflow> {
+    let universe = Universe::parse(input);
+
+    let galaxies = universe.expanded_galaxies(expansion_factor)?;
+
+    let pairs = galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(i, g)| {
+            galaxies
+                .iter()
+                .skip(i + 1)
+                .map(|g2| (g.expanded, g2.expanded))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Widened before adding, as even the two halves of one distance can overflow a usize
+    pairs
+        .into_iter()
+        .map(|((x1, y1), (x2, y2))| x1.abs_diff(x2) as i128 + y1.abs_diff(y2) as i128)
+        .checked_sum(DAY, "summing distances")
+}
+
+/// Holds a parsed universe so it can be queried from the runner's REPL
+pub struct Explorer {
+    universe: Universe,
+}
+
+impl Explorer {
+    pub const QUERIES: &'static [&'static str] = &[
+        "empty - list the empty rows and columns",
+        "galaxies <factor> - show each galaxy's original and expanded coordinates",
+    ];
+
+    pub fn parse(input: &str) -> Self {
+        Self {
+            universe: Universe::parse(input),
+        }
+    }
+
+    pub fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+        match (query, args) {
+            ("empty", []) => Ok(format!(
+                "rows: {:?}\ncols: {:?}",
+                self.universe.get_empty_rows(),
+                self.universe.get_empty_cols()
+            )),
+            ("galaxies", [factor]) => {
+                let factor = factor.parse::<usize>().map_err(|e| e.to_string())?;
+                if factor == 0 {
+                    return Err("The expansion factor must be at least 1".to_string());
+                }
+
+                Ok(self
+                    .universe
+                    .expanded_galaxies(factor)
+                    .map_err(|e| e.to_string())?
+                    .iter()
+                    .map(|g| format!("{:?} -> {:?}", g.original, g.expanded))
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            _ => Err(format!("Unknown query: {}", query)),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use answer::{Answer, OrOverflow, Overflow};
+
+const DAY: u8 = 13;
+
+struct Puzzle {
+    cells: Vec<Vec<char>>,
+}
+
+impl Puzzle {
+    fn parse(input: &str) -> Self {
+        let cells = input
+            .lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Self { cells }
+    }
+
+    // index is a line in the horizontal direction
+    // between two y-indexes
+    fn horizontal_reflection_count_differences(&self, index: usize) -> Option<usize> {
+        if index == 0 {
+            return None;
+        }
+
+        let max_distance = index.min(self.cells.len() - index);
+        let mut differences = 0;
+
+        for distance in 1..=max_distance {
+            let top = index - distance;
+            let bottom = index + distance - 1;
+
+            for x in 0..self.cells[top].len() {
+                if self.cells[top][x] != self.cells[bottom][x] {
+                    differences += 1;
+                }
+            }
+        }
+
+        Some(differences)
+    }
+
+    fn vertical_reflection_count_differences(&self, index: usize) -> Option<usize> {
+        if index == 0 {
+            return None;
+        }
+
+        let max_distance = index.min(self.cells[0].len() - index);
+        let mut differences = 0;
+
+        for distance in 1..=max_distance {
+            let left = index - distance;
+            let right = index + distance - 1;
+
+            for y in 0..self.cells.len() {
+                if self.cells[y][left] != self.cells[y][right] {
+                    differences += 1;
+                }
+            }
+        }
+
+        Some(differences)
+    }
+
+    fn horizontal_reflection(&self, num_differences: usize) -> Option<usize> {
+        (0..self.cells.len())
+            .find(|&i| self.horizontal_reflection_count_differences(i) == Some(num_differences))
+    }
+
+    fn vertical_reflection(&self, num_differences: usize) -> Option<usize> {
+        (0..self.cells[0].len())
+            .find(|&i| self.vertical_reflection_count_differences(i) == Some(num_differences))
+    }
+
+    fn value(&self) -> Result<usize, Overflow> {
+        let hor = self.horizontal_reflection(0);
+        if let Some(hor) = hor {
+            return hor
+                .checked_mul(100)
+                .or_overflow(DAY, "scoring a horizontal reflection");
+        }
+
+        let ver = self.vertical_reflection(0);
+        if let Some(ver) = ver {
+            return Ok(ver);
+        }
+
+        panic!("No reflection found");
+    }
+
+    fn part_2_value(&self) -> Result<usize, Overflow> {
+        let hor = self.horizontal_reflection(1);
+        if let Some(hor) = hor {
+            return hor
+                .checked_mul(100)
+                .or_overflow(DAY, "scoring a horizontal reflection");
+        }
+
+        let ver = self.vertical_reflection(1);
+        if let Some(ver) = ver {
+            return Ok(ver);
+        }
+
+        panic!("No reflection found");
+    }
+}
+
+/// Holds the parsed puzzles so their reflections can be queried from the runner's REPL
+pub struct Explorer {
+    puzzles: Vec<Puzzle>,
+}
+
+impl Explorer {
+    pub const QUERIES: &'static [&'static str] = &[
+        "puzzles - list each puzzle's size",
+        "differences <puzzle> <index> - count the differences when reflecting a puzzle at an index",
+    ];
+
+    pub fn parse(input: &str) -> Self {
+        let puzzles = input.split("\n\n").map(Puzzle::parse).collect();
+
+        Self { puzzles }
+    }
+
+    pub fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+        match (query, args) {
+            ("puzzles", []) => Ok(self
+                .puzzles
+                .iter()
+                .enumerate()
+                .map(|(i, p)| format!("{}: {}x{}", i, p.cells[0].len(), p.cells.len()))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            ("differences", [puzzle, index]) => {
+                let puzzle = puzzle.parse::<usize>().map_err(|e| e.to_string())?;
+                let index = index.parse::<usize>().map_err(|e| e.to_string())?;
+
+                let puzzle = self
+                    .puzzles
+                    .get(puzzle)
+                    .ok_or_else(|| format!("There are only {} puzzles", self.puzzles.len()))?;
+
+                let horizontal = (index < puzzle.cells.len())
+                    .then(|| puzzle.horizontal_reflection_count_differences(index))
+                    .flatten();
+                let vertical = (index < puzzle.cells[0].len())
+                    .then(|| puzzle.vertical_reflection_count_differences(index))
+                    .flatten();
+
+                Ok(format!(
+                    "horizontal: {:?}\nvertical: {:?}",
+                    horizontal, vertical
+                ))
+            }
+            _ => Err(format!("Unknown query: {}", query)),
+        }
+    }
+}
+
+pub fn part_1(sample_input: &str) -> Result<Answer, Overflow> {
+    let puzzles = sample_input
+        .split("\n\n")
+        .map(Puzzle::parse)
+        .collect::<Vec<_>>();
+
+    puzzles.iter().try_fold(Answer(0), |total, p| {
+        total.checked_add(p.value()?, DAY, "summing reflection notes")
+    })
+}
+
+pub fn part_2(sample_input: &str) -> Result<Answer, Overflow> {
+    let puzzles = sample_input
+        .split("\n\n")
+        .map(Puzzle::parse)
+        .collect::<Vec<_>>();
+
+    puzzles.iter().try_fold(Answer(0), |total, p| {
+        total.checked_add(p.part_2_value()?, DAY, "summing reflection notes")
+    })
+}
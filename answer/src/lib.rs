@@ -0,0 +1,211 @@
+use std::fmt;
+
+/// The answer to one part of a day.
+///
+/// Days compute in whatever integer type suits the puzzle, but answers are widened to `i128`
+/// so that they can all be reported the same way, negative ones included.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Answer(pub i128);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Answer {
+    /// Widens `item` and adds it on, for folding an answer together one piece at a time
+    pub fn checked_add(
+        self,
+        item: impl TryInto<i128>,
+        day: u8,
+        step: &str,
+    ) -> Result<Answer, Overflow> {
+        let item = item.try_into().ok().or_overflow(day, step)?;
+
+        self.0.checked_add(item).map(Answer).or_overflow(day, step)
+    }
+}
+
+// Keeps `dbg!` output as terse as it was when answers were plain integers
+impl fmt::Debug for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Which day overflowed, and what it was doing at the time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u8,
+    pub step: String,
+}
+
+impl Overflow {
+    pub fn new(day: u8, step: impl Into<String>) -> Self {
+        Self {
+            day,
+            step: step.into(),
+        }
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} overflowed while {}", self.day, self.step)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// What can go wrong when a day reads its input line by line from a `BufRead` rather than
+/// having it all up front
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    Overflow(Overflow),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "Failed to read input: {}", e),
+            StreamError::Overflow(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<std::io::Error> for StreamError {
+    fn from(value: std::io::Error) -> Self {
+        StreamError::Io(value)
+    }
+}
+
+impl From<Overflow> for StreamError {
+    fn from(value: Overflow) -> Self {
+        StreamError::Overflow(value)
+    }
+}
+
+/// Turns the `None` from a `checked_*` operation into an [`Overflow`]
+pub trait OrOverflow<T> {
+    fn or_overflow(self, day: u8, step: &str) -> Result<T, Overflow>;
+
+    /// Like [`or_overflow`](OrOverflow::or_overflow), but only describes the step once it's
+    /// overflowed, for steps inside hot loops that would otherwise format a label every time
+    fn or_overflow_with(self, day: u8, step: impl FnOnce() -> String) -> Result<T, Overflow>;
+}
+
+impl<T> OrOverflow<T> for Option<T> {
+    fn or_overflow(self, day: u8, step: &str) -> Result<T, Overflow> {
+        self.ok_or_else(|| Overflow::new(day, step))
+    }
+
+    fn or_overflow_with(self, day: u8, step: impl FnOnce() -> String) -> Result<T, Overflow> {
+        self.ok_or_else(|| Overflow::new(day, step()))
+    }
+}
+
+/// Sums and products that widen each item to an [`Answer`] and report an [`Overflow`] instead
+/// of wrapping
+pub trait CheckedAnswer: Iterator + Sized
+where
+    Self::Item: TryInto<i128>,
+{
+    fn checked_sum(mut self, day: u8, step: &str) -> Result<Answer, Overflow> {
+        self.try_fold(Answer(0), |acc, item| acc.checked_add(item, day, step))
+    }
+
+    fn checked_product(mut self, day: u8, step: &str) -> Result<Answer, Overflow> {
+        self.try_fold(Answer(1), |acc, item| {
+            let item = item.try_into().ok().or_overflow(day, step)?;
+
+            acc.0.checked_mul(item).map(Answer).or_overflow(day, step)
+        })
+    }
+}
+
+impl<I> CheckedAnswer for I
+where
+    I: Iterator,
+    I::Item: TryInto<i128>,
+{
+}
+
+/// How far into `whole` its slice `part` starts, for pointing diagnostics at the right column
+pub fn offset(whole: &str, part: &str) -> usize {
+    part.as_ptr() as usize - whole.as_ptr() as usize
+}
+
+/// Enough randomness for fuzzing and generating inputs, without pulling in a dependency for it.
+/// The same seed always gives the same numbers.
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        // A zero state would stay zero forever
+        Self(seed.max(1))
+    }
+
+    /// A number in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        (self.0 % n as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sum_widens_past_u32() {
+        let ans = [u32::MAX, u32::MAX].into_iter().checked_sum(1, "summing");
+
+        assert_eq!(ans, Ok(Answer(2 * u32::MAX as i128)));
+    }
+
+    #[test]
+    fn test_sum_reports_overflow() {
+        let ans = [i128::MAX, 1]
+            .into_iter()
+            .checked_sum(4, "summing card points");
+
+        assert_eq!(ans, Err(Overflow::new(4, "summing card points")));
+        assert_eq!(
+            ans.unwrap_err().to_string(),
+            "Day 4 overflowed while summing card points"
+        );
+    }
+
+    #[test]
+    fn test_product_reports_overflow() {
+        let ans = [u128::MAX].into_iter().checked_product(6, "multiplying");
+
+        assert_eq!(ans, Err(Overflow::new(6, "multiplying")));
+    }
+
+    #[test]
+    fn test_offset_of_subslice() {
+        let line = "Card 1: 41 48";
+
+        assert_eq!(offset(line, &line[8..10]), 8);
+        assert_eq!(offset(line, line), 0);
+    }
+
+    #[test]
+    fn test_xorshift_repeats_for_a_seed() {
+        let draw = |seed| {
+            let mut rng = XorShift::new(seed);
+            (0..5).map(|_| rng.below(100)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(0), vec![0; 5]);
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use answer::Answer;
+
+use crate::solvers::{self, Strategy};
+
+pub struct BenchResult {
+    pub strategy: &'static str,
+    pub answer: Option<Answer>,
+    pub fastest: Duration,
+    pub mean: Duration,
+    pub diagnostics: Vec<String>,
+}
+
+/// Runs each strategy against the same input `iterations` times. A strategy that fails is
+/// only run once, since there is nothing to time.
+pub fn run(strategies: &[&Strategy], input: &str, iterations: u32) -> Vec<BenchResult> {
+    strategies
+        .iter()
+        .map(|strategy| {
+            let mut timings = vec![];
+            let mut solution = solvers::solve(|| (strategy.solve)(input));
+            timings.push(solution.elapsed);
+
+            while solution.answer.is_some() && timings.len() < iterations as usize {
+                solution = solvers::solve(|| (strategy.solve)(input));
+                timings.push(solution.elapsed);
+            }
+
+            BenchResult {
+                strategy: strategy.name,
+                answer: solution.answer,
+                fastest: timings.iter().min().copied().unwrap_or_default(),
+                mean: timings.iter().sum::<Duration>() / timings.len() as u32,
+                diagnostics: solution.diagnostics,
+            }
+        })
+        .collect()
+}
+
+/// The strategies named, or if none are, every strategy that isn't slow. Fails with the first
+/// name that isn't one of them.
+pub fn pick<'a, 'b>(
+    strategies: &'a [Strategy],
+    names: &'b [String],
+) -> Result<Vec<&'a Strategy>, &'b str> {
+    if names.is_empty() {
+        return Ok(strategies.iter().filter(|s| !s.slow).collect());
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            strategies
+                .iter()
+                .find(|s| s.name == name)
+                .ok_or(name.as_str())
+        })
+        .collect()
+}
+
+pub fn print(results: &[BenchResult]) {
+    let width = results
+        .iter()
+        .map(|r| r.strategy.len())
+        .max()
+        .unwrap_or_default()
+        .max("strategy".len());
+
+    println!(
+        "{:<width$}  {:>20}  {:>14}  {:>14}",
+        "strategy", "answer", "fastest", "mean"
+    );
+    for r in results {
+        let answer = r
+            .answer
+            .map(|a| a.to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        println!(
+            "{:<width$}  {:>20}  {:>14}  {:>14}",
+            r.strategy,
+            answer,
+            format!("{:.2?}", r.fastest),
+            format!("{:.2?}", r.mean)
+        );
+        for d in &r.diagnostics {
+            println!("{:<width$}  {}", "", d);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::Write;
+
+use trebuchet::{Policy, Vocabulary, VocabularyError};
+
+use crate::solvers;
+
+/// Looks `name` up among the built in vocabularies, and failing that reads it as a file
+pub fn vocabulary(name: &str, case_insensitive: bool) -> Result<Vocabulary, VocabularyError> {
+    let vocabulary = match Vocabulary::builtin(name) {
+        Some(vocabulary) => vocabulary,
+        None => Vocabulary::load(name)?,
+    };
+
+    Ok(if case_insensitive {
+        vocabulary.case_insensitive()
+    } else {
+        vocabulary
+    })
+}
+
+/// Sums the calibration values, with a warning for every line `policy` had skipped
+pub fn solve(input: &str, vocabulary: &Vocabulary, policy: Policy) -> solvers::Solution {
+    solvers::solve(|| {
+        let calibration =
+            trebuchet::part_2_with(input, vocabulary, policy).map_err(|e| e.to_string())?;
+
+        Ok(solvers::Solved {
+            answer: calibration.total,
+            warnings: calibration
+                .skipped
+                .iter()
+                .map(|s| format!("skipped {}", s))
+                .collect(),
+        })
+    })
+}
+
+/// Writes out which tokens were picked on each line, and marks the lines whose part 1 reading
+/// differs. `color` highlights the tokens with ANSI escapes, otherwise they're bracketed.
+pub fn explain(
+    input: &str,
+    vocabulary: &Vocabulary,
+    mut out: impl Write,
+    color: bool,
+) -> std::io::Result<()> {
+    let (open, close) = if color {
+        ("\x1b[1;33m", "\x1b[0m")
+    } else {
+        ("[", "]")
+    };
+
+    let explanations =
+        trebuchet::explain(input, vocabulary).map_err(|e| std::io::Error::other(e.to_string()))?;
+    for (i, explanation) in explanations.iter().enumerate() {
+        let reading = match (&explanation.part_2, explanation.part_2_value()) {
+            (Some((first, last)), Some(value)) => format!(
+                "{} at {:?}, {} at {:?} = {}",
+                first.value, first.span, last.value, last.span, value
+            ),
+            _ => "no digits".to_string(),
+        };
+        let flag = if explanation.disagrees() {
+            let part_1 = explanation
+                .part_1_value()
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "no digits".to_string());
+            format!("  part 1 reads {}", part_1)
+        } else {
+            String::new()
+        };
+
+        writeln!(
+            out,
+            "{:>5}  {}  {}{}",
+            i + 1,
+            explanation.highlight(open, close),
+            reading,
+            flag
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_warns_about_skipped_lines() {
+        let solution = solve("1\n\nx2", &Vocabulary::numerals(), Policy::Skip);
+
+        assert_eq!(solution.answer, Some(answer::Answer(33)));
+        assert_eq!(
+            solution.diagnostics,
+            vec!["warning: skipped Line 2 has no digits: \"\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut out = vec![];
+        explain("a1b2\nxtwone3four", &Vocabulary::puzzle(), &mut out, false).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "    1  a[1]b[2]  1 at 1..2, 2 at 3..4 = 12\n    \
+             2  x[two]ne3[four]  2 at 1..4, 4 at 7..11 = 24  part 1 reads 33\n"
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::fs::File;
+use std::io::{BufReader, IsTerminal};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use gear_ratios::render;
+use scratchcards::{Cascade, Overrun};
+use trebuchet::{differential, Policy};
+
+mod bench;
+mod calibrate;
+mod repl;
+mod server;
+mod solvers;
+
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Serve `POST /day/{n}/part/{p}` over HTTP, with the puzzle input as the body
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:3000")]
+        addr: String,
+    },
+    /// Solve one part of a day from an input file
+    Solve {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        /// Read the input a line at a time instead of all at once, for days that support it
+        #[arg(long, conflicts_with = "strategy")]
+        stream: bool,
+        /// Which of the part's strategies to use, see `strategies`
+        #[arg(long)]
+        strategy: Option<String>,
+        input: PathBuf,
+    },
+    /// List the strategies each part can be solved with, the default first
+    Strategies {
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Time every strategy for a part against the same input
+    Bench {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        #[arg(long, default_value_t = 10)]
+        iterations: u32,
+        /// Only time these strategies. Without any, every strategy but the slow ones is timed.
+        #[arg(long)]
+        strategy: Vec<String>,
+        input: PathBuf,
+    },
+    /// Sum trebuchet calibration values with another vocabulary's words counting as digits
+    Calibrate {
+        /// puzzle, english, german, french, spanish or roman, or a file of `word = value` lines
+        #[arg(long, default_value = "puzzle")]
+        vocabulary: String,
+        #[arg(long)]
+        case_insensitive: bool,
+        /// What to do with lines that have no digits: error, skip or zero
+        #[arg(long, default_value = "error")]
+        missing: Policy,
+        /// Show the tokens picked on every line instead of the sum
+        #[arg(long)]
+        explain: bool,
+        input: PathBuf,
+    },
+    /// Compare trebuchet's replacing part 2 with the scanning one, printing minimal lines
+    /// they disagree on
+    Differential {
+        /// Check every line up to this long over the letters of the spelled out digits
+        #[arg(long, default_value_t = 5)]
+        max_len: usize,
+        /// How many random lines of words, numerals and letters to check after that
+        #[arg(long, default_value_t = 10_000)]
+        fuzz: usize,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+    /// Write out made up scratchcards shaped like the puzzle's, to time against
+    GenerateCards {
+        #[arg(long, default_value_t = 1_000_000)]
+        count: usize,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+    /// Trace where every scratchcard copy in part 2 came from
+    Cascade {
+        /// Write a Graphviz graph instead of a table
+        #[arg(long)]
+        dot: bool,
+        /// Put the original cards that lead to the most cards first
+        #[arg(long, conflicts_with = "dot")]
+        by_contribution: bool,
+        input: PathBuf,
+    },
+    /// Draw a gear ratios schematic with its part numbers and gears colored in
+    Schematic {
+        /// Write an HTML page, with details of each number and symbol on hover, instead
+        #[arg(long)]
+        html: bool,
+        input: PathBuf,
+    },
+    /// Parse a day's input once and answer queries about it interactively
+    Repl {
+        #[arg(long)]
+        day: u8,
+        input: PathBuf,
+    },
+}
+
+fn main() -> std::io::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Serve { addr } => server::serve(&addr),
+        Command::Solve {
+            day,
+            part,
+            stream,
+            strategy,
+            input,
+        } => {
+            let entry = find_day(day)?;
+
+            let solution = if stream {
+                let solver = entry.streaming_part(part).ok_or_else(|| {
+                    std::io::Error::other(format!("Day {} part {} can't be streamed", day, part))
+                })?;
+                let mut input = BufReader::new(File::open(input)?);
+
+                solvers::solve(|| solver(&mut input))
+            } else {
+                let solver = match &strategy {
+                    Some(name) => entry.strategy(part, name),
+                    None => entry.part(part),
+                };
+                let solver = solver.ok_or_else(|| {
+                    std::io::Error::other(format!(
+                        "Day {} part {} has no strategy {}",
+                        day,
+                        part,
+                        strategy.as_deref().unwrap_or("default")
+                    ))
+                })?;
+                let input = std::fs::read_to_string(input)?;
+
+                solvers::solve(|| solver(&input))
+            };
+
+            report(solution)
+        }
+        Command::Strategies { day } => {
+            for entry in solvers::DAYS {
+                if day.is_some_and(|d| d != entry.number) {
+                    continue;
+                }
+
+                for part in [1, 2] {
+                    let names = entry
+                        .strategies(part)
+                        .unwrap()
+                        .iter()
+                        .map(|s| {
+                            if s.slow {
+                                format!("{} (slow)", s.name)
+                            } else {
+                                s.name.to_string()
+                            }
+                        })
+                        .collect::<Vec<_>>();
+
+                    println!("{} part {}: {}", entry.number, part, names.join(", "));
+                }
+            }
+
+            Ok(())
+        }
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            strategy,
+            input,
+        } => {
+            let strategies = find_day(day)?.strategies(part).ok_or_else(|| {
+                std::io::Error::other(format!("Day {} has no part {}", day, part))
+            })?;
+            let strategies = bench::pick(strategies, &strategy).map_err(|name| {
+                std::io::Error::other(format!(
+                    "Day {} part {} has no strategy {}",
+                    day, part, name
+                ))
+            })?;
+            let input = std::fs::read_to_string(input)?;
+
+            bench::print(&bench::run(&strategies, &input, iterations));
+
+            Ok(())
+        }
+        Command::Calibrate {
+            vocabulary,
+            case_insensitive,
+            missing,
+            explain,
+            input,
+        } => {
+            let vocabulary = calibrate::vocabulary(&vocabulary, case_insensitive)
+                .map_err(|e| std::io::Error::other(e.to_string()))?;
+            let input = std::fs::read_to_string(input)?;
+
+            if explain {
+                let stdout = std::io::stdout();
+                let color = stdout.is_terminal();
+
+                calibrate::explain(&input, &vocabulary, stdout.lock(), color)
+            } else {
+                report(calibrate::solve(&input, &vocabulary, missing))
+            }
+        }
+        Command::Differential {
+            max_len,
+            fuzz,
+            seed,
+        } => {
+            let exhaustive = differential::exhaustive(differential::ALPHABET, max_len);
+            let fuzzed = differential::fuzz(seed, fuzz, 8);
+
+            println!("exhaustive up to {} long:", max_len);
+            print_counterexamples(&exhaustive);
+            println!("fuzzed {} lines:", fuzz);
+            print_counterexamples(&fuzzed);
+
+            Ok(())
+        }
+        Command::GenerateCards { count, seed } => {
+            print!("{}", scratchcards::generate::cards(seed, count));
+
+            Ok(())
+        }
+        Command::Cascade {
+            dot,
+            by_contribution,
+            input,
+        } => {
+            let input = std::fs::read_to_string(input)?;
+            let cascade = Cascade::trace(&input, Overrun::default())
+                .map_err(|e| std::io::Error::other(e.to_string()))?;
+
+            if dot {
+                print!("{}", cascade.dot());
+            } else {
+                print!("{}", cascade.table(by_contribution));
+            }
+
+            Ok(())
+        }
+        Command::Schematic { html, input } => {
+            let input = std::fs::read_to_string(input)?;
+            let schematic = gear_ratios::Schematic::parse(&input)
+                .map_err(|e| std::io::Error::other(e.to_string()))?;
+            let rule = gear_ratios::GearRule::default();
+
+            if html {
+                print!("{}", render::html(&schematic, &rule));
+            } else if std::io::stdout().is_terminal() {
+                print!("{}", render::ansi(&schematic, &rule));
+            } else {
+                // Piped somewhere escapes would only get in the way, so it's the input as is
+                for row in &schematic.cells {
+                    println!("{}", row.iter().collect::<String>());
+                }
+            }
+
+            Ok(())
+        }
+        Command::Repl { day, input } => {
+            let input = std::fs::read_to_string(input)?;
+            let explorer = repl::explorer(day, &input).map_err(std::io::Error::other)?;
+
+            repl::run(
+                explorer.as_ref(),
+                std::io::stdin().lock(),
+                std::io::stdout(),
+            )
+        }
+    }
+}
+
+fn print_counterexamples(counterexamples: &[differential::Counterexample]) {
+    if counterexamples.is_empty() {
+        println!("  none");
+    }
+
+    let show = |value: Option<u32>| value.map_or("nothing".to_string(), |v| v.to_string());
+    for c in counterexamples {
+        println!(
+            "  {}: scan reads {}, replace reads {}",
+            c.line,
+            show(c.expected),
+            show(c.opus)
+        );
+    }
+}
+
+fn report(solution: solvers::Solution) -> std::io::Result<()> {
+    for d in &solution.diagnostics {
+        eprintln!("{}", d);
+    }
+
+    match solution.answer {
+        Some(answer) => {
+            println!("{} ({:?})", answer, solution.elapsed);
+            Ok(())
+        }
+        None => Err(std::io::Error::other("No answer")),
+    }
+}
+
+fn find_day(day: u8) -> std::io::Result<&'static solvers::Day> {
+    solvers::day(day).ok_or_else(|| std::io::Error::other(format!("There is no day {}", day)))
+}
@@ -0,0 +1,188 @@
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+pub trait Explore {
+    fn queries(&self) -> &'static [&'static str];
+    fn query(&self, query: &str, args: &[&str]) -> Result<String, String>;
+}
+
+macro_rules! explore {
+    ($($explorer:ty),* $(,)?) => {
+        $(
+            impl Explore for $explorer {
+                fn queries(&self) -> &'static [&'static str] {
+                    <$explorer>::QUERIES
+                }
+
+                fn query(&self, query: &str, args: &[&str]) -> Result<String, String> {
+                    <$explorer>::query(self, query, args)
+                }
+            }
+        )*
+    };
+}
+
+explore!(
+    cube_conundrum::Explorer,
+    gear_ratios::Explorer,
+    camel_cards::Explorer,
+    haunted_wasteland::Explorer,
+    pipe_maze::Explorer,
+    cosmic_expansion::Explorer,
+    point_of_incidence::Explorer,
+);
+
+/// Parses `input` for `day`'s explorer, failing if the day has none or the input is malformed
+pub fn explorer(day: u8, input: &str) -> Result<Box<dyn Explore>, String> {
+    Ok(match day {
+        2 => Box::new(cube_conundrum::Explorer::parse(input).map_err(|e| e.to_string())?),
+        3 => Box::new(gear_ratios::Explorer::parse(input).map_err(|e| e.to_string())?),
+        7 => Box::new(camel_cards::Explorer::parse(input)),
+        8 => Box::new(haunted_wasteland::Explorer::parse(input)),
+        10 => Box::new(pipe_maze::Explorer::parse(input)),
+        11 => Box::new(cosmic_expansion::Explorer::parse(input)),
+        13 => Box::new(point_of_incidence::Explorer::parse(input)),
+        _ => return Err(format!("Day {} doesn't have any queries", day)),
+    })
+}
+
+/// Reads queries line by line until `quit` or the end of `input`, answering each against the
+/// already parsed puzzle
+pub fn run(
+    explorer: &dyn Explore,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> std::io::Result<()> {
+    write!(output, "> ")?;
+    output.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            None => {}
+            Some("quit" | "exit") => break,
+            Some("help") => {
+                writeln!(output, "help - show this message")?;
+                writeln!(output, "quit - leave the REPL")?;
+                for q in explorer.queries() {
+                    writeln!(output, "{}", q)?;
+                }
+            }
+            Some(query) => {
+                let args = words.collect::<Vec<_>>();
+
+                // Queries run the same code as the solvers, which will happily panic on input
+                // they don't expect. That shouldn't throw away the parsed puzzle.
+                let result = panic::catch_unwind(AssertUnwindSafe(|| explorer.query(query, &args)));
+                match result {
+                    Ok(Ok(answer)) => writeln!(output, "{}", answer)?,
+                    Ok(Err(e)) => writeln!(output, "error: {}", e)?,
+                    Err(_) => writeln!(output, "error: query panicked")?,
+                }
+            }
+        }
+
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Runs `queries` against `day`'s explorer for `puzzle`, returning everything written
+    fn session(day: u8, puzzle: &str, queries: &str) -> String {
+        let explorer = explorer(day, puzzle).unwrap();
+
+        let mut output = vec![];
+        run(explorer.as_ref(), queries.as_bytes(), &mut output).unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_queries_parsed_puzzle() {
+        let puzzle = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\n";
+        let explorer = explorer(7, puzzle).unwrap();
+
+        let mut output = vec![];
+        run(
+            explorer.as_ref(),
+            "hands two-pair\njoker-hands four-of-a-kind\nhands nonsense\nquit\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output,
+            "> KK677 28\nKTJJT 220\n> T55J5 684\nKTJJT 220\nQQQJA 483\n> error: Unknown hand type nonsense, expected one of high-card, one-pair, two-pair, three-of-a-kind, full-house, four-of-a-kind, five-of-a-kind\n> "
+        );
+    }
+
+    #[test]
+    fn test_node_neighbours() {
+        let output = session(
+            8,
+            include_str!("../../08-haunted-wasteland/src/sample1.input"),
+            "neighbours CCC\nneighbours QQQ\n",
+        );
+
+        assert_eq!(
+            output,
+            "> left: ZZZ\nright: GGG\nreached from: AAA\n> error: No node named QQQ\n> "
+        );
+    }
+
+    #[test]
+    fn test_pipe_connections() {
+        let output = session(
+            10,
+            include_str!("../../10-pipe-maze/src/simple_sample.input"),
+            "connections 2 1\nconnections 0 0\n",
+        );
+
+        assert_eq!(
+            output,
+            "> Position { x: 1, y: 1 }\nPosition { x: 3, y: 1 }\n> error: (0, 0) is not a pipe\n> "
+        );
+    }
+
+    #[test]
+    fn test_galaxy_coordinates() {
+        let output = session(
+            11,
+            include_str!("../../11-cosmic-expansion/src/sample.input"),
+            "galaxies 2\ngalaxies 0\n",
+        );
+
+        let mut lines = output.lines();
+        assert_eq!(lines.next(), Some("> (0, 3) -> (0, 4)"));
+        assert_eq!(lines.nth(7), Some("(9, 4) -> (11, 5)"));
+        assert_eq!(
+            lines.next(),
+            Some("> error: The expansion factor must be at least 1")
+        );
+    }
+
+    #[test]
+    fn test_reflection_differences() {
+        let output = session(
+            13,
+            include_str!("../../13-point-of-incidence/src/sample.input"),
+            "differences 0 5\ndifferences 1 4\ndifferences 2 0\n",
+        );
+
+        assert_eq!(
+            output,
+            "> horizontal: Some(10)\nvertical: Some(0)\n\
+             > horizontal: Some(0)\nvertical: Some(17)\n\
+             > error: There are only 2 puzzles\n> "
+        );
+    }
+}
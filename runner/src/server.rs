@@ -0,0 +1,194 @@
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::solvers;
+
+#[derive(Debug, Serialize)]
+struct SolveResponse {
+    day: u8,
+    name: &'static str,
+    part: u8,
+    answer: Option<String>,
+    elapsed_micros: u128,
+    diagnostics: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+pub fn serve(addr: &str) -> std::io::Result<()> {
+    let server = Server::http(addr).map_err(std::io::Error::other)?;
+    eprintln!("Listening on http://{}", server.server_addr());
+
+    run(&server);
+
+    Ok(())
+}
+
+pub fn run(server: &Server) {
+    for request in server.incoming_requests() {
+        if let Err(e) = handle(request) {
+            eprintln!("Failed to respond: {}", e);
+        }
+    }
+}
+
+fn handle(mut request: Request) -> std::io::Result<()> {
+    let Some((day, part, strategy)) = parse_route(request.url()) else {
+        return respond(request, 404, &error("Expected /day/{n}/part/{p}"));
+    };
+
+    if *request.method() != Method::Post {
+        return respond(request, 405, &error("Only POST is supported"));
+    }
+
+    let Some(entry) = solvers::day(day) else {
+        return respond(request, 404, &error("No solver for that day"));
+    };
+    let solver = match &strategy {
+        Some(name) => entry.strategy(part, name),
+        None => entry.part(part),
+    };
+    let Some(solver) = solver else {
+        return respond(request, 404, &error("No solver for that part and strategy"));
+    };
+
+    let mut input = String::new();
+    if request.as_reader().read_to_string(&mut input).is_err() {
+        return respond(request, 400, &error("Body must be valid UTF-8"));
+    }
+
+    let solution = solvers::solve(|| solver(&input));
+    let status = if solution.answer.is_some() { 200 } else { 422 };
+
+    let body = SolveResponse {
+        day,
+        name: entry.name,
+        part,
+        answer: solution.answer.map(|a| a.to_string()),
+        elapsed_micros: solution.elapsed.as_micros(),
+        diagnostics: solution.diagnostics,
+    };
+
+    respond(request, status, &body)
+}
+
+/// Splits `/day/{n}/part/{p}?strategy={name}` into its pieces, the strategy being optional
+fn parse_route(url: &str) -> Option<(u8, u8, Option<String>)> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    let strategy = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("strategy="))
+        .map(|s| s.to_string());
+
+    match segments.as_slice() {
+        ["day", day, "part", part] => Some((day.parse().ok()?, part.parse().ok()?, strategy)),
+        _ => None,
+    }
+}
+
+fn error(message: &str) -> ErrorResponse {
+    ErrorResponse {
+        error: message.to_string(),
+    }
+}
+
+fn respond(request: Request, status: u16, body: &impl Serialize) -> std::io::Result<()> {
+    let body = serde_json::to_string(body).map_err(std::io::Error::other)?;
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+
+    request.respond(
+        Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    fn post(path: &str, body: &str) -> (u16, serde_json::Value) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        let handle = std::thread::spawn(move || {
+            let request = server.recv().unwrap();
+            handle(request).unwrap();
+        });
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        handle.join().unwrap();
+
+        let status = response[9..12].parse().unwrap();
+        let (_, json) = response.split_once("\r\n\r\n").unwrap();
+
+        (status, serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn test_solves_sample() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+        let (status, json) = post("/day/6/part/1", input);
+
+        assert_eq!(status, 200);
+        assert_eq!(json["answer"], "288");
+        assert_eq!(json["diagnostics"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_reports_parse_failures() {
+        let (status, json) = post("/day/4/part/1", "not a scratchcard");
+
+        assert_eq!(status, 422);
+        assert_eq!(json["answer"], serde_json::Value::Null);
+        assert_eq!(json["diagnostics"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_reports_warnings() {
+        let (status, json) = post("/day/4/part/1", "Card 1: 5 7 5 | 5 9");
+
+        assert_eq!(status, 200);
+        assert_eq!(json["answer"], "1");
+        assert_eq!(
+            json["diagnostics"],
+            serde_json::json!(["warning: Line 1, column 13: 5 is already a winning number"])
+        );
+    }
+
+    #[test]
+    fn test_picks_strategy() {
+        let input = include_str!("../../11-cosmic-expansion/src/sample.input");
+        let (status, json) = post("/day/11/part/1?strategy=expand", input);
+
+        assert_eq!(status, 200);
+        assert_eq!(json["answer"], "374");
+
+        let (status, _) = post("/day/11/part/1?strategy=nonsense", input);
+        assert_eq!(status, 404);
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        let (status, _) = post("/day/25/part/1", "");
+
+        assert_eq!(status, 404);
+    }
+}
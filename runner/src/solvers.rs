@@ -0,0 +1,366 @@
+use std::fmt::Display;
+use std::io::BufRead;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use answer::Answer;
+use scratchcards::{Overrun, Scored, ScratchcardError};
+
+pub type Solver = fn(&str) -> Result<Solved, String>;
+pub type StreamingSolver = fn(&mut dyn BufRead) -> Result<Solved, String>;
+
+/// An answer, and anything odd about the input that didn't stop it being found
+#[derive(Debug)]
+pub struct Solved {
+    pub answer: Answer,
+    pub warnings: Vec<String>,
+}
+
+impl From<Answer> for Solved {
+    fn from(answer: Answer) -> Self {
+        Self {
+            answer,
+            warnings: vec![],
+        }
+    }
+}
+
+/// One named way of solving a part
+pub struct Strategy {
+    pub name: &'static str,
+    pub solve: Solver,
+    /// Too slow on real input to be worth timing unless asked for by name
+    pub slow: bool,
+}
+
+impl Strategy {
+    pub const fn new(name: &'static str, solve: Solver) -> Self {
+        Self {
+            name,
+            solve,
+            slow: false,
+        }
+    }
+
+    pub const fn slow(name: &'static str, solve: Solver) -> Self {
+        Self {
+            name,
+            solve,
+            slow: true,
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    /// The first strategy for each part is the one used unless another is asked for
+    pub part_1: &'static [Strategy],
+    pub part_2: &'static [Strategy],
+    /// Only the days that can fold their input in a line at a time have these
+    pub streaming: Option<(StreamingSolver, StreamingSolver)>,
+}
+
+impl Day {
+    pub fn strategies(&self, part: u8) -> Option<&'static [Strategy]> {
+        match part {
+            1 => Some(self.part_1),
+            2 => Some(self.part_2),
+            _ => None,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        self.strategies(part)?.first().map(|s| s.solve)
+    }
+
+    pub fn strategy(&self, part: u8, name: &str) -> Option<Solver> {
+        self.strategies(part)?
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| s.solve)
+    }
+
+    pub fn streaming_part(&self, part: u8) -> Option<StreamingSolver> {
+        let (part_1, part_2) = self.streaming?;
+
+        match part {
+            1 => Some(part_1),
+            2 => Some(part_2),
+            _ => None,
+        }
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        name: "trebuchet",
+        part_1: &[
+            Strategy::new("bytes", |input| {
+                report(trebuchet::part_1_bytes(input.as_bytes()))
+            }),
+            Strategy::new("parallel", |input| {
+                report(trebuchet::part_1_parallel(input.as_bytes()))
+            }),
+            Strategy::new("chars", |input| report(trebuchet::part_1(input))),
+        ],
+        part_2: &[
+            Strategy::new("scan", |input| report(trebuchet::part_2(input))),
+            Strategy::new("find", |input| report(trebuchet::part_2_find(input))),
+            // Misreads overlapping words like `twone`, see `differential`
+            Strategy::new("replace", |input| report(trebuchet::part_2_opus(input))),
+        ],
+        streaming: Some((
+            |input| report(trebuchet::part_1_streaming(input)),
+            |input| report(trebuchet::part_2_streaming(input)),
+        )),
+    },
+    Day {
+        number: 2,
+        name: "cube-conundrum",
+        part_1: &[Strategy::new("default", |input| {
+            diagnose(cube_conundrum::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            diagnose(cube_conundrum::part_2(input))
+        })],
+        streaming: Some((
+            |input| diagnose(cube_conundrum::part_1_streaming(input)),
+            |input| diagnose(cube_conundrum::part_2_streaming(input)),
+        )),
+    },
+    Day {
+        number: 3,
+        name: "gear-ratios",
+        part_1: &[
+            Strategy::new("index", |input| diagnose(gear_ratios::part_1(input))),
+            Strategy::new("scan", |input| diagnose(gear_ratios::part_1_scan(input))),
+        ],
+        part_2: &[
+            Strategy::new("index", |input| diagnose(gear_ratios::part_2(input))),
+            Strategy::new("scan", |input| diagnose(gear_ratios::part_2_scan(input))),
+        ],
+        streaming: None,
+    },
+    Day {
+        number: 4,
+        name: "scratchcards",
+        part_1: &[
+            Strategy::new("bitset", |input| scored(scratchcards::part_1_scored(input))),
+            Strategy::new("hashset", |input| {
+                report(scratchcards::part_1_hashset(input))
+            }),
+        ],
+        part_2: &[
+            Strategy::new("bitset", |input| {
+                scored(scratchcards::part_2_with(input, Overrun::default()))
+            }),
+            Strategy::new("hashset", |input| {
+                report(scratchcards::part_2_hashset(input))
+            }),
+        ],
+        streaming: Some((
+            |input| scored(scratchcards::part_1_streaming_scored(input)),
+            |input| {
+                scored(scratchcards::part_2_streaming_with(
+                    input,
+                    Overrun::default(),
+                ))
+            },
+        )),
+    },
+    Day {
+        number: 5,
+        name: "if-you-give-a-seed-a-fertilizer",
+        part_1: &[Strategy::new("default", |input| {
+            report(if_you_give_a_seed_a_fertilizer::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(if_you_give_a_seed_a_fertilizer::part_2(input))
+        })],
+        streaming: None,
+    },
+    Day {
+        number: 6,
+        name: "wait-for-it",
+        part_1: &[Strategy::new("default", |input| {
+            report(wait_for_it::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(wait_for_it::part_2(input))
+        })],
+        streaming: None,
+    },
+    Day {
+        number: 7,
+        name: "camel-cards",
+        part_1: &[Strategy::new("default", |input| {
+            report(camel_cards::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(camel_cards::part_2(input))
+        })],
+        streaming: None,
+    },
+    Day {
+        number: 8,
+        name: "haunted-wasteland",
+        part_1: &[Strategy::new("default", |input| {
+            report(haunted_wasteland::part_1(input))
+        })],
+        part_2: &[
+            Strategy::new("lcm", |input| report(haunted_wasteland::part2_try2(input))),
+            // Walks every ghost in lockstep, which takes far too long on real input
+            Strategy::slow("naive", |input| report(haunted_wasteland::part_2(input))),
+        ],
+        streaming: None,
+    },
+    Day {
+        number: 9,
+        name: "mirage-maintenance",
+        part_1: &[Strategy::new("default", |input| {
+            report(mirage_maintenance::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(mirage_maintenance::part_2(input))
+        })],
+        streaming: Some((
+            |input| report(mirage_maintenance::part_1_streaming(input)),
+            |input| report(mirage_maintenance::part_2_streaming(input)),
+        )),
+    },
+    Day {
+        number: 10,
+        name: "pipe-maze",
+        part_1: &[Strategy::new("default", |input| {
+            report(pipe_maze::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(pipe_maze::part_2(input))
+        })],
+        streaming: None,
+    },
+    Day {
+        number: 11,
+        name: "cosmic-expansion",
+        part_1: &[
+            Strategy::new("shift", |input| report(cosmic_expansion::part_1(input))),
+            Strategy::new("expand", |input| {
+                report(cosmic_expansion::part_1_expanded(input))
+            }),
+        ],
+        part_2: &[Strategy::new("default", |input| {
+            report(cosmic_expansion::part_2(input, 1_000_000))
+        })],
+        streaming: None,
+    },
+    Day {
+        number: 12,
+        name: "hot-springs",
+        part_1: &[Strategy::new("default", |input| {
+            report(hot_springs::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(hot_springs::part_2(input))
+        })],
+        streaming: Some((
+            |input| report(hot_springs::part_1_streaming(input)),
+            |input| report(hot_springs::part_2_streaming(input)),
+        )),
+    },
+    Day {
+        number: 13,
+        name: "point-of-incidence",
+        part_1: &[Strategy::new("default", |input| {
+            report(point_of_incidence::part_1(input))
+        })],
+        part_2: &[Strategy::new("default", |input| {
+            report(point_of_incidence::part_2(input))
+        })],
+        streaming: None,
+    },
+];
+
+fn report(result: Result<Answer, impl Display>) -> Result<Solved, String> {
+    result.map(Solved::from).map_err(|e| e.to_string())
+}
+
+/// Like [`report`], but renders the labels miette errors carry, so a parse error shows where
+/// in the input it happened
+fn diagnose(result: miette::Result<Answer>) -> Result<Solved, String> {
+    result.map(Solved::from).map_err(|e| format!("{:?}", e))
+}
+
+/// Like [`report`], passing on the warnings about the cards
+fn scored(result: Result<Scored, ScratchcardError>) -> Result<Solved, String> {
+    let scored = result.map_err(|e| e.to_string())?;
+
+    let mut warnings = scored
+        .warnings
+        .kept
+        .iter()
+        .map(|w| w.to_string())
+        .collect::<Vec<_>>();
+    if scored.warnings.dropped > 0 {
+        warnings.push(format!(
+            "{} more warnings not shown",
+            scored.warnings.dropped
+        ));
+    }
+
+    Ok(Solved {
+        answer: scored.total,
+        warnings,
+    })
+}
+
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+    pub diagnostics: Vec<String>,
+}
+
+// Most of the days still `unwrap` their way through parsing, so a panic is
+// treated the same as an error: it becomes a diagnostic instead of taking
+// down the caller.
+pub fn solve(solver: impl FnOnce() -> Result<Solved, String>) -> Solution {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(solver));
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(Ok(solved)) => Solution {
+            answer: Some(solved.answer),
+            elapsed,
+            diagnostics: solved
+                .warnings
+                .iter()
+                .map(|w| format!("warning: {}", w))
+                .collect(),
+        },
+        Ok(Err(e)) => Solution {
+            answer: None,
+            elapsed,
+            diagnostics: vec![e],
+        },
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "solver panicked".to_string());
+
+            Solution {
+                answer: None,
+                elapsed,
+                diagnostics: vec![format!("panicked: {}", message)],
+            }
+        }
+    }
+}